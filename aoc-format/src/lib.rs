@@ -0,0 +1,207 @@
+//! `#[derive(LineFormat)]` for `advent_of_code::parse`: declare the shape of an
+//! input line with a format string and get its parser for free.
+//!
+//! ```ignore
+//! #[derive(LineFormat)]
+//! #[line("Card {id}: {win_nb} | {my_nb}")]
+//! pub struct Match {
+//!     id: u32,
+//!     win_nb: HashSet<u32>,
+//!     my_nb: HashSet<u32>,
+//! }
+//! ```
+//!
+//! Each `{field}` is parsed with its `FromPart` implementation, `{field:sep}`
+//! splits the field on `sep` and collects the items, e.g. `{sets:;}`. Whitespace
+//! around literals is optional and can be repeated in the input, `{{` and `}}`
+//! are literal braces.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr};
+
+struct Placeholder {
+    field: Ident,
+    separator: Option<String>,
+}
+
+/// Split `format` into the literals around the placeholders, there is always one
+/// more literal than placeholders
+fn parse_format(format: &LitStr) -> Result<(Vec<String>, Vec<Placeholder>), Error> {
+    let text = format.value();
+    let error = |message: &str| Error::new(format.span(), message);
+    let mut literals = vec![String::new()];
+    let mut placeholders = vec![];
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literals.last_mut().unwrap().push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literals.last_mut().unwrap().push('}');
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) if c != '{' => placeholder.push(c),
+                        // a `{` cannot be in a placeholder, the previous one was not closed
+                        _ => {
+                            return Err(error(&format!(
+                                "unclosed placeholder `{{{placeholder}`, use `{{{{` for a literal brace"
+                            )))
+                        }
+                    }
+                }
+                let (name, separator) = match placeholder.split_once(':') {
+                    Some((name, separator)) => (name, Some(separator.to_string())),
+                    None => (placeholder.as_str(), None),
+                };
+                if !placeholders.is_empty() && literals.last().unwrap().is_empty() {
+                    return Err(error("placeholders must be separated by a literal"));
+                }
+                let field = syn::parse_str::<Ident>(name.trim())
+                    .map_err(|_| error(&format!("invalid field name `{name}`")))?;
+                placeholders.push(Placeholder { field, separator });
+                literals.push(String::new());
+            }
+            '}' => return Err(error("unmatched `}`, use `}}` for a literal brace")),
+            c => literals.last_mut().unwrap().push(c),
+        }
+    }
+    Ok((literals, placeholders))
+}
+
+#[proc_macro_derive(LineFormat, attributes(line))]
+pub fn derive_line_format(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let name = &input.ident;
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            name,
+            "`LineFormat` only supports structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            name,
+            "`LineFormat` only supports structs with named fields",
+        ));
+    };
+    let attribute = input
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("line"))
+        .ok_or_else(|| Error::new_spanned(name, "missing `#[line(\"<format>\")]`"))?;
+    let format: LitStr = attribute.parse_args()?;
+    let (literals, placeholders) = parse_format(&format)?;
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        if !placeholders.iter().any(|p| &p.field == ident) {
+            return Err(Error::new_spanned(
+                ident,
+                format!("`{ident}` is missing from the format"),
+            ));
+        }
+    }
+    let parse = quote!(::advent_of_code::parse);
+    let parts: Vec<_> = (0..placeholders.len())
+        .map(|i| Ident::new(&format!("part{i}"), Span::call_site()))
+        .collect();
+    let values = placeholders.iter().zip(&parts).map(|(placeholder, part)| {
+        let field = &placeholder.field;
+        let expected = format!("the `{field}` field");
+        match &placeholder.separator {
+            Some(separator) => quote! {
+                #field: line
+                    .list(#part, #separator)
+                    .into_iter()
+                    .map(|item| #parse::FromPart::from_part(line, item, #expected))
+                    .collect::<Result<_, _>>()?
+            },
+            None => quote!(#field: #parse::FromPart::from_part(line, #part, #expected)?),
+        }
+    });
+    let format_text = format.value();
+
+    Ok(quote! {
+        impl #parse::FromPart for #name {
+            fn from_part<'a>(
+                line: &#parse::Line<'a>,
+                part: &'a str,
+                _expected: &str,
+            ) -> Result<Self, #parse::ParseError> {
+                let [#(#parts),*] = line.split_format(part, #format_text, &[#(#literals),*])?[..] else {
+                    unreachable!("`split_format` returns a part per placeholder")
+                };
+                Ok(#name { #(#values),* })
+            }
+        }
+
+        impl ::std::str::FromStr for #name {
+            type Err = #parse::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                #parse::from_str(s)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::parse_format;
+    use proc_macro2::Span;
+    use syn::LitStr;
+
+    fn parse(format: &str) -> Result<(Vec<String>, Vec<String>), String> {
+        let (literals, placeholders) =
+            parse_format(&LitStr::new(format, Span::call_site())).map_err(|e| e.to_string())?;
+        let fields = placeholders.iter().map(|p| p.field.to_string()).collect();
+        Ok((literals, fields))
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(
+            parse("Card {id}: {win_nb:,} {{x}}").unwrap(),
+            (
+                vec!["Card ".to_string(), ": ".to_string(), " {x}".to_string()],
+                vec!["id".to_string(), "win_nb".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_errors() {
+        assert_eq!(
+            parse("Card {id: {x}").unwrap_err(),
+            "unclosed placeholder `{id: `, use `{{` for a literal brace"
+        );
+        assert_eq!(
+            parse("Card {id").unwrap_err(),
+            "unclosed placeholder `{id`, use `{{` for a literal brace"
+        );
+        assert_eq!(
+            parse("Card {id}}").unwrap_err(),
+            "unmatched `}`, use `}}` for a literal brace"
+        );
+        assert_eq!(
+            parse("{a}{b}").unwrap_err(),
+            "placeholders must be separated by a literal"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::runner::{self, Solution};
+use crate::{input, YEARS};
+
+/// Known answers of each input, stored in `answers.toml` as
+///
+/// ```toml
+/// [2023.day1.f58ffde9cad37b81]
+/// part1 = "54667"
+/// part2 = "54203"
+/// ```
+///
+/// where the last key is the `fingerprint` of the input, so answers of
+/// different inputs for the same day can live side by side.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: HashMap<(u32, u32, String), [Option<String>; 2]>,
+}
+
+/// `answers.toml` at the root of the crate, whatever the current directory is
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut res = Answers::default();
+        let mut current = None;
+        for (i, line) in input.lines().enumerate() {
+            let error =
+                |expected: &str| format!("line {}: expected {expected}, found {line:?}", i + 1);
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("a `]` ending the section"))?;
+                let key = match header.split('.').collect::<Vec<_>>()[..] {
+                    [year, day, fingerprint] => year.parse().ok().zip(
+                        day.strip_prefix("day")
+                            .and_then(|d| d.parse().ok())
+                            .map(|day| (day, fingerprint.to_string())),
+                    ),
+                    _ => None,
+                };
+                let (year, (day, fingerprint)) =
+                    key.ok_or_else(|| error("a `[<year>.day<N>.<fingerprint>]` section"))?;
+                current = Some((year, day, fingerprint));
+                continue;
+            }
+            let key = current
+                .clone()
+                .ok_or_else(|| error("a `[<year>.day<N>.<fingerprint>]` section"))?;
+            let (part, answer) = line
+                .split_once('=')
+                .ok_or_else(|| error("a `partN = \"answer\"` entry"))?;
+            let part = match part.trim() {
+                "part1" => 0,
+                "part2" => 1,
+                _ => return Err(error("`part1` or `part2`")),
+            };
+            let answer = answer.trim().trim_matches('"').to_string();
+            res.entries.entry(key).or_default()[part] = Some(answer);
+        }
+        Ok(res)
+    }
+
+    /// Load the answers file, a missing file simply has no answers
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(input) => Answers::parse(&input).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("cannot read {}: {e}", path.display())),
+        }
+    }
+
+    /// Every `(year, day)` with at least one answer, sorted
+    pub fn days(&self) -> Vec<(u32, u32)> {
+        let mut res: Vec<_> = self
+            .entries
+            .keys()
+            .map(|&(year, day, _)| (year, day))
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32, fingerprint: &str) -> Option<&str> {
+        self.entries
+            .get(&(year, day, fingerprint.to_string()))
+            .and_then(|parts| parts.get(part as usize - 1)?.as_deref())
+    }
+}
+
+/// Stable 64 bits FNV-1a hash of an input, ignoring line endings and trailing whitespace
+pub fn fingerprint(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for (i, line) in input.trim_end().lines().enumerate() {
+        let separator: &[u8] = if i == 0 { b"" } else { b"\n" };
+        for byte in separator.iter().chain(line.trim_end().as_bytes()) {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ECheck {
+    Pass,
+    Fail { expected: String, answer: String },
+    MissingAnswer { answer: String },
+    MissingInput,
+    Error(String),
+}
+
+impl ECheck {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, ECheck::Fail { .. } | ECheck::Error(_))
+    }
+}
+
+impl Display for ECheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ECheck::Pass => write!(f, "pass"),
+            ECheck::Fail { expected, answer } => {
+                write!(f, "FAIL, expected {expected} but got {answer}")
+            }
+            ECheck::MissingAnswer { answer } => write!(f, "missing answer, got {answer}"),
+            ECheck::MissingInput => write!(f, "missing input"),
+            ECheck::Error(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Solve `input` and compare the answer with the known one
+pub fn check(answers: &Answers, year: u32, solution: &Solution, input: Option<&str>) -> ECheck {
+    let Some(input) = input else {
+        return ECheck::MissingInput;
+    };
+    let answer = match runner::run(solution, input) {
+        Ok(answer) => answer.answer,
+        Err(e) => return ECheck::Error(e.to_string()),
+    };
+    match answers.get(year, solution.day, solution.part, &fingerprint(input)) {
+        Some(expected) if expected == answer => ECheck::Pass,
+        Some(expected) => ECheck::Fail {
+            expected: expected.to_string(),
+            answer,
+        },
+        None => ECheck::MissingAnswer { answer },
+    }
+}
+
+/// Check one part of a day against `answers.toml` in a test, an answer not
+/// recorded yet skipping the test like a missing input does
+pub fn assert_answer(year: u32, day: u32, part: u32, input: &str) {
+    let solution = YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .and_then(|y| y.solution(day, part))
+        .unwrap_or_else(|| panic!("no solution for {year} day {day} part {part}"));
+    let answers = Answers::load(answers_path()).unwrap();
+    match check(&answers, year, solution, Some(input)) {
+        ECheck::MissingAnswer { answer } => input::skip(format_args!(
+            "no answer for {year} day {day} part {part} in {}, got {answer}",
+            answers_path().display()
+        )),
+        res => assert!(!res.is_failure(), "{year} day {day} part {part}: {res}"),
+    }
+}
+
+/// Test both parts of a day against `answers.toml` on the personal input
+#[macro_export]
+macro_rules! answer_tests {
+    ($year:expr, $day:expr) => {
+        #[test]
+        fn test_answer_part1() {
+            let input = $crate::input_or_skip!($year, $day);
+            $crate::answers::assert_answer($year, $day, 1, &input);
+        }
+
+        #[test]
+        fn test_answer_part2() {
+            let input = $crate::input_or_skip!($year, $day);
+            $crate::answers::assert_answer($year, $day, 2, &input);
+        }
+    };
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{check, fingerprint, Answers, ECheck};
+    use crate::y2023;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# comment
+            [2023.day1.abc]
+            part1 = \"12\" # inline comment
+            part2 = 34
+
+            [2023.day2.def]
+            part2 = \"5\"",
+        )
+        .unwrap();
+        assert_eq!(answers.get(2023, 1, 1, "abc"), Some("12"));
+        assert_eq!(answers.get(2023, 1, 2, "abc"), Some("34"));
+        assert_eq!(answers.get(2023, 2, 1, "def"), None);
+        assert_eq!(answers.get(2023, 2, 2, "def"), Some("5"));
+        assert_eq!(answers.get(2023, 2, 2, "abc"), None);
+        assert_eq!(answers.days(), [(2023, 1), (2023, 2)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("part1 = 1").is_err());
+        assert!(Answers::parse("[2023.1.abc]").is_err());
+        assert!(Answers::parse("[2023.day1.abc]\npart3 = 1").is_err());
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(fingerprint("1 2\n3 4\n"), fingerprint("1 2\r\n3 4"));
+        assert_ne!(fingerprint("1 2\n3 4"), fingerprint("1 2\n3 5"));
+    }
+
+    #[test]
+    fn test_check() {
+        let input = "0 3 6 9 12 15";
+        let answers = Answers::parse(&format!(
+            "[2023.day9.{}]\npart1 = 18\npart2 = 0",
+            fingerprint(input)
+        ))
+        .unwrap();
+        let day9 = |part| y2023::YEAR.solution(9, part).unwrap();
+        assert_eq!(check(&answers, 2023, day9(1), Some(input)), ECheck::Pass);
+        assert_eq!(
+            check(&answers, 2023, day9(2), Some(input)),
+            ECheck::Fail {
+                expected: "0".to_string(),
+                answer: "-3".to_string()
+            }
+        );
+        assert_eq!(check(&answers, 2023, day9(1), None), ECheck::MissingInput);
+    }
+}
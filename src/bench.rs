@@ -0,0 +1,376 @@
+use std::{fmt::Display, fs, path::Path, time::Duration};
+
+use crate::runner::{self, Answer, ERunError, Solution};
+
+/// Distribution of the timings of one step over every iteration of a benchmark
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Nearest-rank statistics of `timings`, which must not be empty
+    pub fn new(mut timings: Vec<Duration>) -> Self {
+        assert!(!timings.is_empty(), "no timings to compute statistics on");
+        timings.sort();
+        let rank = |percent: usize| timings[(timings.len() * percent).div_ceil(100).max(1) - 1];
+        Stats {
+            min: timings[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, p95 {:?}",
+            self.min, self.median, self.p95
+        )
+    }
+}
+
+/// Timings of a solution, the generator and the runner being measured separately
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Measure {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub iterations: u32,
+    pub generator: Stats,
+    pub runner: Stats,
+}
+
+impl Display for Measure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} - Part {} ({} iterations)\n\tgenerator: {},\n\trunner: {}",
+            self.day, self.part, self.iterations, self.generator, self.runner
+        )
+    }
+}
+
+/// Solve `input` once to warm up then `iterations` times, returning the answer
+/// of the warm up run with the statistics of the others
+pub fn bench(
+    year: u32,
+    solution: &Solution,
+    input: &str,
+    iterations: u32,
+) -> Result<(Answer, Measure), ERunError> {
+    let answer = runner::run(solution, input)?;
+    let mut generator = Vec::with_capacity(iterations as usize);
+    let mut runner = Vec::with_capacity(iterations as usize);
+    for _ in 0..iterations.max(1) {
+        let res = runner::run(solution, input)?;
+        generator.push(res.generator);
+        runner.push(res.runner);
+    }
+    let measure = Measure {
+        year,
+        day: solution.day,
+        part: solution.part,
+        iterations: generator.len() as u32,
+        generator: Stats::new(generator),
+        runner: Stats::new(runner),
+    };
+    Ok((answer, measure))
+}
+
+/// Columns of a report, durations are in nanoseconds
+const COLUMNS: [&str; 10] = [
+    "year",
+    "day",
+    "part",
+    "iterations",
+    "generator_min_ns",
+    "generator_median_ns",
+    "generator_p95_ns",
+    "runner_min_ns",
+    "runner_median_ns",
+    "runner_p95_ns",
+];
+
+fn row(measure: &Measure) -> [u64; 10] {
+    let ns = |d: Duration| d.as_nanos() as u64;
+    [
+        measure.year as u64,
+        measure.day as u64,
+        measure.part as u64,
+        measure.iterations as u64,
+        ns(measure.generator.min),
+        ns(measure.generator.median),
+        ns(measure.generator.p95),
+        ns(measure.runner.min),
+        ns(measure.runner.median),
+        ns(measure.runner.p95),
+    ]
+}
+
+fn from_row(values: [u64; 10]) -> Measure {
+    let d = Duration::from_nanos;
+    Measure {
+        year: values[0] as u32,
+        day: values[1] as u32,
+        part: values[2] as u32,
+        iterations: values[3] as u32,
+        generator: Stats {
+            min: d(values[4]),
+            median: d(values[5]),
+            p95: d(values[6]),
+        },
+        runner: Stats {
+            min: d(values[7]),
+            median: d(values[8]),
+            p95: d(values[9]),
+        },
+    }
+}
+
+/// Format of a report file, given by its extension
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EFormat {
+    Json,
+    Csv,
+}
+
+impl EFormat {
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Ok(EFormat::Json),
+            Some("csv") => Ok(EFormat::Csv),
+            _ => Err(format!(
+                "cannot guess the format of {}, expected a .json or .csv file",
+                path.display()
+            )),
+        }
+    }
+}
+
+/// Write `measures` as a JSON array of flat objects, one per solution, or as a
+/// CSV file with the same columns
+pub fn format_report(measures: &[Measure], format: EFormat) -> String {
+    let mut res = String::new();
+    match format {
+        EFormat::Json => {
+            res.push_str("[\n");
+            for (i, measure) in measures.iter().enumerate() {
+                let fields: Vec<_> = COLUMNS
+                    .iter()
+                    .zip(row(measure))
+                    .map(|(column, value)| format!("\"{column}\": {value}"))
+                    .collect();
+                let separator = if i + 1 < measures.len() { "," } else { "" };
+                res.push_str(&format!("  {{{}}}{separator}\n", fields.join(", ")));
+            }
+            res.push_str("]\n");
+        }
+        EFormat::Csv => {
+            res.push_str(&COLUMNS.join(","));
+            res.push('\n');
+            for measure in measures {
+                let values: Vec<_> = row(measure).iter().map(|v| v.to_string()).collect();
+                res.push_str(&values.join(","));
+                res.push('\n');
+            }
+        }
+    }
+    res
+}
+
+/// Read back a report written by `format_report`
+pub fn parse_report(input: &str, format: EFormat) -> Result<Vec<Measure>, String> {
+    let records: Vec<Vec<(String, &str)>> = match format {
+        EFormat::Json => {
+            let body = input
+                .trim()
+                .strip_prefix('[')
+                .and_then(|b| b.strip_suffix(']'))
+                .ok_or("expected a JSON array")?;
+            body.split('}')
+                .map(|record| record.trim().trim_start_matches(',').trim())
+                .filter(|record| !record.is_empty())
+                .map(|record| {
+                    let record = record
+                        .strip_prefix('{')
+                        .ok_or_else(|| format!("expected a JSON object, found {record:?}"))?;
+                    record
+                        .split(',')
+                        .map(|field| {
+                            let (key, value) = field.split_once(':').ok_or_else(|| {
+                                format!("expected `\"key\": value`, found {field:?}")
+                            })?;
+                            Ok((key.trim().trim_matches('"').to_string(), value.trim()))
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, String>>()?
+        }
+        EFormat::Csv => {
+            let mut lines = input.lines().filter(|l| !l.trim().is_empty());
+            let header: Vec<_> = lines
+                .next()
+                .ok_or("missing the CSV header")?
+                .split(',')
+                .map(|c| c.trim().to_string())
+                .collect();
+            lines
+                .map(|line| {
+                    header
+                        .iter()
+                        .cloned()
+                        .zip(line.split(',').map(str::trim))
+                        .collect()
+                })
+                .collect()
+        }
+    };
+    records
+        .into_iter()
+        .map(|record| {
+            let mut values = [0; 10];
+            for (value, column) in values.iter_mut().zip(COLUMNS) {
+                let (_, text) = record
+                    .iter()
+                    .find(|(key, _)| key == column)
+                    .ok_or_else(|| format!("missing `{column}`"))?;
+                *value = text
+                    .parse()
+                    .map_err(|_| format!("expected a number for `{column}`, found {text:?}"))?;
+            }
+            Ok(from_row(values))
+        })
+        .collect()
+}
+
+pub fn write_report(path: &Path, measures: &[Measure]) -> Result<(), String> {
+    let report = format_report(measures, EFormat::from_path(path)?);
+    fs::write(path, report).map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+pub fn read_report(path: &Path) -> Result<Vec<Measure>, String> {
+    let format = EFormat::from_path(path)?;
+    let input =
+        fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    parse_report(&input, format).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Relative change of the median from `old` to `new`, e.g. `-25.0%` for a speedup
+fn change(old: Duration, new: Duration) -> String {
+    if old.is_zero() {
+        return "n/a".to_string();
+    }
+    let ratio = new.as_secs_f64() / old.as_secs_f64() - 1.;
+    format!("{:+.1}%", ratio * 100.)
+}
+
+/// Compare the medians of every solution measured in both reports
+pub fn diff(old: &[Measure], new: &[Measure]) -> Vec<String> {
+    let key = |m: &Measure| (m.year, m.day, m.part);
+    let mut res = vec![];
+    for measure in new {
+        let Some(before) = old.iter().find(|m| key(m) == key(measure)) else {
+            res.push(format!(
+                "{} Day {} - Part {}: only in the new report",
+                measure.year, measure.day, measure.part
+            ));
+            continue;
+        };
+        res.push(format!(
+            "{} Day {} - Part {}\n\tgenerator: {:?} -> {:?} ({}),\n\trunner: {:?} -> {:?} ({})",
+            measure.year,
+            measure.day,
+            measure.part,
+            before.generator.median,
+            measure.generator.median,
+            change(before.generator.median, measure.generator.median),
+            before.runner.median,
+            measure.runner.median,
+            change(before.runner.median, measure.runner.median),
+        ));
+    }
+    for measure in old.iter().filter(|m| !new.iter().any(|n| key(n) == key(m))) {
+        res.push(format!(
+            "{} Day {} - Part {}: only in the old report",
+            measure.year, measure.day, measure.part
+        ));
+    }
+    res
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::time::Duration;
+
+    use super::{bench, diff, format_report, parse_report, EFormat, Measure, Stats};
+    use crate::y2023;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        let stats = Stats::new(ms(&(1..=100).collect::<Vec<_>>()));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(Stats::new(ms(&[7])).p95, Duration::from_millis(7));
+    }
+
+    fn measure(day: u32, generator: u64, runner: u64) -> Measure {
+        Measure {
+            year: 2023,
+            day,
+            part: 1,
+            iterations: 10,
+            generator: Stats::new(ms(&[generator])),
+            runner: Stats::new(ms(&[runner])),
+        }
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let measures = vec![measure(1, 1, 2), measure(5, 3, 40)];
+        for format in [EFormat::Json, EFormat::Csv] {
+            let report = format_report(&measures, format);
+            assert_eq!(parse_report(&report, format).unwrap(), measures);
+        }
+        assert_eq!(parse_report("[]", EFormat::Json).unwrap(), vec![]);
+        assert!(parse_report("[{\"year\": 2023}]", EFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let lines = diff(
+            &[measure(1, 1, 2), measure(5, 4, 40)],
+            &[measure(5, 4, 30), measure(6, 1, 1)],
+        );
+        assert_eq!(
+            lines,
+            [
+                "2023 Day 5 - Part 1\n\tgenerator: 4ms -> 4ms (+0.0%),\n\trunner: 40ms -> 30ms (-25.0%)",
+                "2023 Day 6 - Part 1: only in the new report",
+                "2023 Day 1 - Part 1: only in the old report",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        let solution = y2023::YEAR.solution(9, 1).unwrap();
+        let (answer, measure) = bench(2023, solution, "0 3 6 9 12 15", 5).unwrap();
+        assert_eq!(answer.answer, "18");
+        assert_eq!((measure.day, measure.part, measure.iterations), (9, 1, 5));
+        assert!(measure.runner.min <= measure.runner.median);
+        assert!(measure.runner.median <= measure.runner.p95);
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    bench,
+    runner::{ERunError, Solution},
+};
+
+/// Runs of a solution whose median time is compared with its budget
+pub const ITERATIONS: u32 = 10;
+
+/// Time budget of each part, generator included, stored in `budgets.toml` as
+///
+/// ```toml
+/// [2023.day11]
+/// part1 = "2ms"
+/// part2 = "5ms"
+/// ```
+///
+/// Budgets hold for release builds on any input, unlike answers they are not
+/// tied to the fingerprint of an input.
+#[derive(Debug, Default)]
+pub struct Budgets {
+    entries: HashMap<(u32, u32), [Option<Duration>; 2]>,
+}
+
+/// `budgets.toml` at the root of the crate, whatever the current directory is
+pub fn budgets_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("budgets.toml")
+}
+
+/// Parse a duration such as `500us`, `1.5ms` or `2s`
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let number_end = text.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (number, unit) = text.split_at(number_end);
+    let number: f64 = number.parse().ok()?;
+    let seconds = match unit.trim() {
+        "ns" => number / 1e9,
+        "us" | "µs" => number / 1e6,
+        "ms" => number / 1e3,
+        "s" => number,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(seconds))
+}
+
+impl Budgets {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut res = Budgets::default();
+        let mut current = None;
+        for (i, line) in input.lines().enumerate() {
+            let error =
+                |expected: &str| format!("line {}: expected {expected}, found {line:?}", i + 1);
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let key = header.strip_suffix(']').and_then(|h| h.split_once('.'));
+                let key = key.and_then(|(year, day)| {
+                    let day = day.strip_prefix("day")?.parse().ok()?;
+                    Some((year.parse().ok()?, day))
+                });
+                current = Some(key.ok_or_else(|| error("a `[<year>.day<N>]` section"))?);
+                continue;
+            }
+            let key = current.ok_or_else(|| error("a `[<year>.day<N>]` section"))?;
+            let (part, budget) = line
+                .split_once('=')
+                .ok_or_else(|| error("a `partN = \"<duration>\"` entry"))?;
+            let part = match part.trim() {
+                "part1" => 0,
+                "part2" => 1,
+                _ => return Err(error("`part1` or `part2`")),
+            };
+            let budget = parse_duration(budget.trim().trim_matches('"'))
+                .ok_or_else(|| error("a duration such as \"5ms\""))?;
+            res.entries.entry(key).or_default()[part] = Some(budget);
+        }
+        Ok(res)
+    }
+
+    /// Load the budgets file, a missing file simply has no budgets
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(input) => Budgets::parse(&input).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Budgets::default()),
+            Err(e) => Err(format!("cannot read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<Duration> {
+        self.entries
+            .get(&(year, day))
+            .and_then(|parts| *parts.get(part as usize - 1)?)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum EBudget {
+    Within { time: Duration, budget: Duration },
+    Exceeded { time: Duration, budget: Duration },
+    Missing { time: Duration },
+}
+
+impl EBudget {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, EBudget::Exceeded { .. })
+    }
+}
+
+impl Display for EBudget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EBudget::Within { time, budget } => write!(f, "{time:?} within {budget:?}"),
+            EBudget::Exceeded { time, budget } => {
+                write!(f, "OVER BUDGET, {time:?} for {budget:?}")
+            }
+            EBudget::Missing { time } => write!(f, "{time:?} without budget"),
+        }
+    }
+}
+
+/// Compare the median time of `solution` on `input` with its budget
+pub fn check(
+    budgets: &Budgets,
+    year: u32,
+    solution: &Solution,
+    input: &str,
+) -> Result<EBudget, ERunError> {
+    let (_, measure) = bench::bench(year, solution, input, ITERATIONS)?;
+    let time = measure.generator.median + measure.runner.median;
+    Ok(match budgets.get(year, solution.day, solution.part) {
+        Some(budget) if time <= budget => EBudget::Within { time, budget },
+        Some(budget) => EBudget::Exceeded { time, budget },
+        None => EBudget::Missing { time },
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::time::Duration;
+
+    use super::{budgets_path, check, parse_duration, Budgets, EBudget};
+    use crate::{input, y2023, YEARS};
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("5ms"), Some(Duration::from_millis(5)));
+        assert_eq!(parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("250us"), Some(Duration::from_micros(250)));
+        assert_eq!(parse_duration("250µs"), Some(Duration::from_micros(250)));
+        assert_eq!(parse_duration("5"), None);
+        assert_eq!(parse_duration("5 minutes"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let budgets = Budgets::parse(
+            "[2023.day11]
+            part1 = \"2ms\"
+            part2 = \"5ms\" # quadratic",
+        )
+        .unwrap();
+        assert_eq!(budgets.get(2023, 11, 1), Some(Duration::from_millis(2)));
+        assert_eq!(budgets.get(2023, 11, 2), Some(Duration::from_millis(5)));
+        assert_eq!(budgets.get(2023, 10, 2), None);
+        assert!(Budgets::parse("[2023.11]\npart1 = \"2ms\"").is_err());
+        assert!(Budgets::parse("[2023.day11]\npart1 = \"fast\"").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let budgets = Budgets::parse("[2023.day9]\npart1 = \"100s\"\npart2 = \"0ns\"").unwrap();
+        let day9 = |part| y2023::YEAR.solution(9, part).unwrap();
+        let res = check(&budgets, 2023, day9(1), "0 3 6 9 12 15").unwrap();
+        assert!(matches!(res, EBudget::Within { .. }));
+        let res = check(&budgets, 2023, day9(2), "0 3 6 9 12 15").unwrap();
+        assert!(res.is_failure());
+    }
+
+    /// Budgets only make sense for optimized builds, run `cargo test --release`
+    #[test]
+    fn test_budgets() {
+        if cfg!(debug_assertions) {
+            input::skip("budgets are only checked by `cargo test --release`");
+            return;
+        }
+        let budgets = Budgets::load(budgets_path()).unwrap();
+        let mut failures = vec![];
+        for year in YEARS {
+            for solution in year.solutions {
+                let Some(input) = input::load(year.year, solution.day).unwrap() else {
+                    let path = input::input_path(year.year, solution.day);
+                    input::skip(format_args!("{} not found", path.display()));
+                    continue;
+                };
+                let res = check(&budgets, year.year, solution, &input).unwrap();
+                if res.is_failure() {
+                    failures.push(format!(
+                        "{} day {} part {}: {res}",
+                        year.year, solution.day, solution.part
+                    ));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
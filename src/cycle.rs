@@ -0,0 +1,268 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::math;
+
+/// Shape of the sequence `start, step(start), step(step(start)), ...` of a
+/// deterministic state machine: `prefix` states are seen once, then the next
+/// `len` ones repeat forever. `hits` are the steps below `prefix + len` whose
+/// state matches the predicate given to the detection, in increasing order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: u64,
+    pub len: u64,
+    pub hits: Vec<u64>,
+}
+
+impl Cycle {
+    /// The step below `prefix + len` reaching the same state as step `n`
+    pub fn index(&self, n: u64) -> u64 {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.len
+        }
+    }
+
+    /// Whether the state after `n` steps matches the predicate
+    pub fn is_hit(&self, n: u64) -> bool {
+        self.hits.binary_search(&self.index(n)).is_ok()
+    }
+
+    /// The hits repeated every `len` steps
+    pub fn cycle_hits(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hits.iter().copied().filter(|&h| h >= self.prefix)
+    }
+}
+
+/// Floyd's tortoise and hare: constant memory, about three walks of the sequence
+pub fn floyd<S: Clone + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    predicate: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+    // the meeting point is a multiple of the cycle length away from the start
+    let mut prefix = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    let mut len = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        len += 1;
+    }
+    with_hits(start, step, prefix, len, predicate)
+}
+
+/// Brent's algorithm: constant memory, fewer steps than `floyd`
+pub fn brent<S: Clone + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    predicate: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+    }
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start.clone();
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    with_hits(start, step, prefix, len, predicate)
+}
+
+/// Remember the step at which every state was first seen: a single walk of the
+/// sequence, at the cost of storing each state
+pub fn hashed<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut predicate: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut hits = vec![];
+    let mut state = start;
+    let mut n = 0;
+    let prefix = loop {
+        if let Some(&first) = seen.get(&state) {
+            break first;
+        }
+        seen.insert(state.clone(), n);
+        if predicate(&state) {
+            hits.push(n);
+        }
+        state = step(&state);
+        n += 1;
+    };
+    Cycle {
+        prefix,
+        len: n - prefix,
+        hits,
+    }
+}
+
+fn with_hits<S>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    prefix: u64,
+    len: u64,
+    mut predicate: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let mut hits = vec![];
+    let mut state = start;
+    for n in 0..prefix + len {
+        if predicate(&state) {
+            hits.push(n);
+        }
+        state = step(&state);
+    }
+    Cycle { prefix, len, hits }
+}
+
+/// First step at which the predicates of all the machines hold together, `None`
+/// when it never happens or only after `u64::MAX` steps
+pub fn first_common_hit(cycles: &[Cycle]) -> Option<u64> {
+    let Some(longest) = cycles.iter().max_by_key(|c| c.prefix) else {
+        return Some(0);
+    };
+    // While the longest prefix lasts, only its own hits are candidates
+    if let Some(n) = longest
+        .hits
+        .iter()
+        .take_while(|&&h| h < longest.prefix)
+        .find(|&&h| cycles.iter().all(|c| c.is_hit(h)))
+    {
+        return Some(*n);
+    }
+    // After it every machine is in its cycle: each combination of cycle hits is
+    // a system of congruences
+    let mut congruences = vec![(0, 1)];
+    for cycle in cycles {
+        congruences = congruences
+            .iter()
+            .flat_map(|&(r, m)| {
+                cycle
+                    .cycle_hits()
+                    .filter_map(move |h| math::crt(&[(r as i64, m), (h as i64, cycle.len)]))
+            })
+            .collect();
+    }
+    congruences
+        .into_iter()
+        .filter_map(|(r, m)| {
+            let k = longest.prefix.saturating_sub(r).div_ceil(m);
+            m.checked_mul(k)?.checked_add(r)
+        })
+        .min()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{brent, first_common_hit, floyd, hashed, Cycle};
+
+    // 0 1 2 3 4 5 2 3 4 5 ...
+    fn step(x: &u64) -> u64 {
+        if *x == 5 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn test_detection() {
+        let expected = Cycle {
+            prefix: 2,
+            len: 4,
+            hits: vec![0, 2, 4],
+        };
+        let even = |x: &u64| x.is_multiple_of(2);
+        assert_eq!(floyd(0, step, even), expected);
+        assert_eq!(brent(0, step, even), expected);
+        assert_eq!(hashed(0, step, even), expected);
+        // pure cycle and fixed point
+        let expected = Cycle {
+            prefix: 0,
+            len: 4,
+            hits: vec![1],
+        };
+        assert_eq!(brent(2, step, |&x| x == 3), expected);
+        let fixed = Cycle {
+            prefix: 0,
+            len: 1,
+            hits: vec![0],
+        };
+        assert_eq!(floyd(7, |&x| x, |_| true), fixed);
+        assert_eq!(hashed(7, |&x| x, |_| true), fixed);
+    }
+
+    #[test]
+    fn test_algorithms_agree() {
+        for start in 0..50 {
+            let step = |x: &u64| (x * x + 1) % 1009;
+            let mod7 = |x: &u64| x.is_multiple_of(7);
+            let cycle = hashed(start, step, mod7);
+            assert_eq!(floyd(start, step, mod7), cycle);
+            assert_eq!(brent(start, step, mod7), cycle);
+        }
+    }
+
+    #[test]
+    fn test_is_hit() {
+        let cycle = hashed(0, step, |&x| x == 4);
+        assert_eq!(cycle.cycle_hits().collect::<Vec<_>>(), vec![4]);
+        let hits: Vec<u64> = (0..20).filter(|&n| cycle.is_hit(n)).collect();
+        assert_eq!(hits, vec![4, 8, 12, 16]);
+        assert_eq!(cycle.index(1_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_first_common_hit() {
+        let cycle = |prefix, len, hits: &[u64]| Cycle {
+            prefix,
+            len,
+            hits: hits.to_vec(),
+        };
+        // hits at 2 mod 3 and 3 mod 4 from the start
+        let a = cycle(0, 3, &[2]);
+        let b = cycle(0, 4, &[3]);
+        assert_eq!(first_common_hit(&[a.clone(), b.clone()]), Some(11));
+        // a hit in a prefix is only seen once
+        let c = cycle(5, 4, &[3, 5]);
+        assert_eq!(first_common_hit(&[b.clone(), c.clone()]), Some(3));
+        assert_eq!(first_common_hit(&[a.clone(), c]), Some(5));
+        // the ghosts of day 8 whose first hit is not their cycle length
+        let d = cycle(0, 4, &[1]);
+        let e = cycle(2, 6, &[3]);
+        assert_eq!(first_common_hit(&[d, e]), Some(9));
+        assert_eq!(
+            first_common_hit(&[cycle(0, 2, &[0]), cycle(0, 2, &[1])]),
+            None
+        );
+        assert_eq!(first_common_hit(&[]), Some(0));
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{runner, YEARS};
+
+/// A puzzle example from `examples/<year>/dayN/*.txt`. The file starts with a
+/// header giving the expected answers, ended by a `---` line:
+///
+/// ```text
+/// part1: 142
+/// part2: 281
+/// ---
+/// 1abc2
+/// pqr3stu8vwx
+/// ```
+///
+/// A part missing from the header is not checked for this example.
+#[derive(Debug)]
+pub struct Example {
+    pub path: PathBuf,
+    pub answers: [Option<String>; 2],
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(path: PathBuf, text: &str) -> Result<Self, String> {
+        let mut answers = [None, None];
+        let mut lines = text.lines();
+        for line in lines.by_ref() {
+            let line = line.trim();
+            if line == "---" {
+                let input = lines.collect::<Vec<_>>().join("\n");
+                return Ok(Example {
+                    path,
+                    answers,
+                    input,
+                });
+            }
+            let part = match line.split_once(':') {
+                Some(("part1", answer)) => Some((0, answer)),
+                Some(("part2", answer)) => Some((1, answer)),
+                _ => None,
+            };
+            let (part, answer) = part.ok_or_else(|| {
+                format!(
+                    "{}: expected `part1: <answer>`, `part2: <answer>` or `---`, found {line:?}",
+                    path.display()
+                )
+            })?;
+            answers[part] = Some(answer.trim().to_string());
+        }
+        Err(format!(
+            "{}: missing the `---` ending the header",
+            path.display()
+        ))
+    }
+}
+
+pub fn examples_dir(year: u32, day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("day{day}"))
+}
+
+/// Every example of a day, sorted by file name
+pub fn load(year: u32, day: u32) -> Result<Vec<Example>, String> {
+    let dir = examples_dir(year, day);
+    let entries = fs::read_dir(&dir).map_err(|e| format!("cannot read {}: {e}", dir.display()))?;
+    let mut paths = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("cannot read {}: {e}", dir.display()))?;
+    paths.retain(|p| p.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let text = fs::read_to_string(&path)
+                .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+            Example::parse(path, &text)
+        })
+        .collect()
+}
+
+/// Solve every example of a day and describe each wrong answer
+pub fn check(year: u32, day: u32) -> Result<Vec<String>, String> {
+    let year_solutions = YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .ok_or_else(|| format!("no solutions for {year}"))?;
+    let examples = load(year, day)?;
+    if examples.is_empty() {
+        return Err(format!(
+            "no example in {}",
+            examples_dir(year, day).display()
+        ));
+    }
+    let mut failures = vec![];
+    for example in examples {
+        for (part, expected) in (1..).zip(&example.answers) {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let Some(solution) = year_solutions.solution(day, part) else {
+                failures.push(format!(
+                    "{}: no solution for part {part}",
+                    example.path.display()
+                ));
+                continue;
+            };
+            match runner::run(solution, &example.input) {
+                Ok(answer) if &answer.answer == expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{} part {part}: expected {expected} but got {}",
+                    example.path.display(),
+                    answer.answer
+                )),
+                Err(e) => failures.push(format!("{} part {part}: {e}", example.path.display())),
+            }
+        }
+    }
+    Ok(failures)
+}
+
+/// Test every example of `examples/<year>/dayN/`
+#[macro_export]
+macro_rules! example_tests {
+    ($year:expr, $day:expr) => {
+        #[test]
+        fn test_examples() {
+            let failures = $crate::example::check($year, $day).unwrap();
+            assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+        }
+    };
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::Example;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse(PathBuf::new(), "part2: 281\n---\n#..\n..#\n").unwrap();
+        assert_eq!(example.answers, [None, Some("281".to_string())]);
+        assert_eq!(example.input, "#..\n..#");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Example::parse(PathBuf::new(), "part1: 1\n1 2 3").is_err());
+        assert!(Example::parse(PathBuf::new(), "part3: 1\n---\n1 2 3").is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+/// Why an answer is what it is: a table with a row per item of the input that
+/// counts in it, and how the rows combine into the answer. Printed by
+/// `run --explain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakdown {
+    pub summary: String,
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Breakdown {
+    pub fn new(summary: &str, columns: &[&'static str]) -> Self {
+        Breakdown {
+            summary: summary.to_string(),
+            columns: columns.to_vec(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: &[&dyn Display]) {
+        assert_eq!(
+            row.len(),
+            self.columns.len(),
+            "a row of {:?} has {} cells",
+            self.columns,
+            row.len()
+        );
+        self.rows
+            .push(row.iter().map(|cell| cell.to_string()).collect());
+    }
+}
+
+impl Display for Breakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.summary)?;
+        let widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let cells = self.rows.iter().map(|row| row[i].chars().count());
+                cells.chain([column.len()]).max().unwrap()
+            })
+            .collect();
+        let header = self.columns.iter().map(|c| c.to_string()).collect();
+        for row in [header].iter().chain(&self.rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{cell:width$}"))
+                .collect();
+            writeln!(f, "  {}", cells.join("  ").trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::Breakdown;
+
+    #[test]
+    fn test_display() {
+        let mut breakdown = Breakdown::new("sum of the scores", &["card", "score"]);
+        breakdown.push(&[&1, &8]);
+        breakdown.push(&[&"long card", &true]);
+        assert_eq!(
+            breakdown.to_string(),
+            "sum of the scores\n  card       score\n  1          8\n  long card  true\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has 1 cells")]
+    fn test_row_length() {
+        Breakdown::new("", &["a", "b"]).push(&[&1]);
+    }
+}
@@ -0,0 +1,257 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed 2D point, `y` growing downwards like the rows of a puzzle input,
+/// so that moving out of a grid never underflows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Taxicab distance, the number of `Dir4` steps between the two points
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Number of `Dir8` steps between the two points
+    pub fn chebyshev(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Dir4::ALL.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Dir8::ALL.into_iter().map(move |d| self + d)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for (i64, i64) {
+    fn from(p: Point) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+/// The 4 orthogonal directions, in clockwise order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir4::Up => Point::new(0, -1),
+            Dir4::Right => Point::new(1, 0),
+            Dir4::Down => Point::new(0, 1),
+            Dir4::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+}
+
+/// The 8 directions including diagonals, in clockwise order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir8::Up => Point::new(0, -1),
+            Dir8::UpRight => Point::new(1, -1),
+            Dir8::Right => Point::new(1, 0),
+            Dir8::DownRight => Point::new(1, 1),
+            Dir8::Down => Point::new(0, 1),
+            Dir8::DownLeft => Point::new(-1, 1),
+            Dir8::Left => Point::new(-1, 0),
+            Dir8::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Eighth of a turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+impl Add<Dir4> for Point {
+    type Output = Point;
+    fn add(self, rhs: Dir4) -> Point {
+        self + rhs.delta()
+    }
+}
+
+impl Add<Dir8> for Point {
+    type Output = Point;
+    fn add(self, rhs: Dir8) -> Point {
+        self + rhs.delta()
+    }
+}
+
+impl AddAssign<Dir4> for Point {
+    fn add_assign(&mut self, rhs: Dir4) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Dir8> for Point {
+    fn add_assign(&mut self, rhs: Dir8) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{Dir4, Dir8, Point};
+
+    #[test]
+    fn test_operators() {
+        let mut p = Point::new(1, 2);
+        assert_eq!(p + Point::new(3, -4), Point::new(4, -2));
+        assert_eq!(p - Point::new(3, -4), Point::new(-2, 6));
+        assert_eq!(p * 3, Point::new(3, 6));
+        assert_eq!(-p, Point::new(-1, -2));
+        p += Dir4::Up;
+        assert_eq!(p, Point::new(1, 1));
+        p += Dir8::DownLeft;
+        assert_eq!(p, Point::new(0, 2));
+        assert_eq!(Point::from((5, 6)), Point::new(5, 6));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, 6), Point::new(5, 11));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 5);
+        assert_eq!(b.manhattan(a), 9);
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::ORIGIN);
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        for dir in Dir8::ALL {
+            assert_eq!(
+                dir.turn_right().turn_right().turn_right().turn_right(),
+                dir.opposite()
+            );
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::ORIGIN);
+        }
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Point::ORIGIN.neighbours8().count(), 8);
+    }
+}
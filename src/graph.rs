@@ -0,0 +1,241 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Result of a BFS or of Dijkstra: the distance of every reached node and the
+/// node it was reached from, to rebuild shortest paths
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    pub distances: HashMap<N, u64>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    /// Shortest path from a start to `goal`, both included
+    pub fn path(&self, goal: &N) -> Option<Vec<N>> {
+        self.distances.get(goal)?;
+        let mut res = vec![goal.clone()];
+        while let Some(parent) = self.parents.get(res.last().unwrap()) {
+            res.push(parent.clone());
+        }
+        res.reverse();
+        Some(res)
+    }
+
+    /// A reached node with the largest distance
+    pub fn farthest(&self) -> Option<(&N, u64)> {
+        self.distances
+            .iter()
+            .max_by_key(|(_, &d)| d)
+            .map(|(n, &d)| (n, d))
+    }
+}
+
+/// Distances in number of edges from the closest of `starts` to every reachable node
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    Search { distances, parents }
+}
+
+/// Weighted distances from the closest of `starts`, `neighbours` giving each
+/// next node with the cost of the edge leading to it
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        distances.insert(start.clone(), 0);
+        heap.push(Reverse((0, start)));
+    }
+    while let Some(Reverse((distance, node))) = heap.pop() {
+        if distances[&node] < distance {
+            continue;
+        }
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if distances.get(&next).is_none_or(|&d| next_distance < d) {
+                distances.insert(next.clone(), next_distance);
+                parents.insert(next.clone(), node.clone());
+                heap.push(Reverse((next_distance, next)));
+            }
+        }
+    }
+    Search { distances, parents }
+}
+
+/// Shortest path from `start` to the first node matching `is_goal` with its
+/// cost. `heuristic` must never overestimate the remaining cost, a heuristic
+/// of 0 behaves like Dijkstra.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search {
+        distances: HashMap::from([(start.clone(), 0)]),
+        parents: HashMap::new(),
+    };
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+    while let Some(Reverse((_, distance, node))) = heap.pop() {
+        if search.distances[&node] < distance {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((search.path(&node)?, distance));
+        }
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_none_or(|&d| next_distance < d)
+            {
+                search.distances.insert(next.clone(), next_distance);
+                search.parents.insert(next.clone(), node.clone());
+                heap.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    next,
+                )));
+            }
+        }
+    }
+    None
+}
+
+/// Group `nodes` into connected components, `neighbours` being symmetric.
+/// Components are in the order of their first node in `nodes`.
+pub fn components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut res = vec![];
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component: Vec<N> = bfs([node], &mut neighbours).distances.into_keys().collect();
+        seen.extend(component.iter().cloned());
+        res.push(component);
+    }
+    res
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::HashMap;
+
+    use super::{astar, bfs, components, dijkstra};
+
+    fn graph() -> HashMap<char, Vec<(char, u64)>> {
+        HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('a', 7), ('c', 10), ('d', 15)]),
+            ('c', vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+            ('d', vec![('b', 15), ('c', 11), ('e', 6)]),
+            ('e', vec![('d', 6), ('f', 9)]),
+            ('f', vec![('a', 14), ('c', 2), ('e', 9)]),
+            ('x', vec![('y', 1)]),
+            ('y', vec![('x', 1)]),
+        ])
+    }
+
+    #[test]
+    fn test_bfs() {
+        let graph = graph();
+        let search = bfs(['a'], |n| graph[n].iter().map(|&(next, _)| next));
+        assert_eq!(search.distance(&'e'), Some(2));
+        assert_eq!(search.distance(&'x'), None);
+        assert_eq!(search.path(&'a'), Some(vec!['a']));
+        assert_eq!(search.path(&'e').unwrap().len(), 3);
+        assert_eq!(search.farthest().map(|(_, d)| d), Some(2));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = graph();
+        let search = dijkstra(['a'], |n| graph[n].clone());
+        assert_eq!(search.distance(&'e'), Some(20));
+        assert_eq!(search.distance(&'d'), Some(20));
+        assert_eq!(search.path(&'e'), Some(vec!['a', 'c', 'f', 'e']));
+        assert_eq!(search.path(&'y'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let graph = graph();
+        let res = astar('a', |n| graph[n].clone(), |_| 0, |&n| n == 'e');
+        assert_eq!(res, Some((vec!['a', 'c', 'f', 'e'], 20)));
+        assert_eq!(astar('a', |n| graph[n].clone(), |_| 0, |&n| n == 'x'), None);
+        // grid walk with the manhattan distance as heuristic
+        let res = astar(
+            (0i64, 0i64),
+            |&(x, y)| [((x + 1, y), 1), ((x, y + 1), 1)],
+            |&(x, y)| (5 - x).unsigned_abs() + (3 - y).unsigned_abs(),
+            |&p| p == (5, 3),
+        );
+        assert_eq!(res.map(|(path, cost)| (path.len(), cost)), Some((9, 8)));
+    }
+
+    #[test]
+    fn test_components() {
+        let graph = graph();
+        let mut nodes: Vec<_> = graph.keys().copied().collect();
+        nodes.sort();
+        let mut res = components(nodes, |n| graph[n].iter().map(|&(next, _)| next));
+        for component in &mut res {
+            component.sort();
+        }
+        assert_eq!(
+            res,
+            vec![vec!['a', 'b', 'c', 'd', 'e', 'f'], vec!['x', 'y']]
+        );
+    }
+}
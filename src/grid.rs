@@ -0,0 +1,289 @@
+use std::{
+    fmt::Debug,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    geom::{Dir4, Point},
+    parse::{self, ParseError},
+    union_find::UnionFind,
+};
+
+/// Dense 2D grid stored row by row
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "grid cells do not match its size"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parse one cell per char, `to_cell` returns `None` for an invalid char
+    /// which is then reported as expecting `expected`.
+    pub fn parse_with(
+        day: u32,
+        input: &str,
+        expected: &str,
+        mut to_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut grid_width = None;
+        let mut height = 0;
+        for line in parse::lines(day, input) {
+            let line_width = line.text.chars().count();
+            let width = *grid_width.get_or_insert(line_width);
+            if width != line_width {
+                return Err(line.error(line.text, format!("a row of {width} cells")));
+            }
+            cells.extend(line.parse_chars(line.text, expected, &mut to_cell)?);
+            height += 1;
+        }
+        let width =
+            grid_width.ok_or_else(|| parse::missing(day, input, "at least one grid row"))?;
+        Ok(Grid::new(width, height, cells))
+    }
+
+    /// Parse one cell per char with the `FromStr` implementation of the cell type
+    pub fn parse(day: u32, input: &str, expected: &str) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+    {
+        Grid::parse_with(day, input, expected, |c| {
+            c.encode_utf8(&mut [0; 4]).parse().ok()
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Positions are signed so that neighbours of the borders can be computed
+    /// without underflow and simply fall outside of the grid
+    pub fn contains(&self, Point { x, y }: Point) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn idx(&self, pos: Point) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.idx(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.idx(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position of the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// First position whose cell matches `predicate`, row by row
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, c)| predicate(c)).map(|(p, _)| p)
+    }
+
+    /// Up, right, down and left neighbours that are inside the grid
+    pub fn neighbours4(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        pos.neighbours4()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// Neighbours inside the grid including diagonals, clockwise from the top
+    pub fn neighbours8(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        pos.neighbours8()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of row `y`, none when `y` is outside of the grid
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        let cells = if y < self.height {
+            &self.cells[y * self.width..(y + 1) * self.width]
+        } else {
+            &[]
+        };
+        cells.iter()
+    }
+
+    /// Cells of column `x`, none when `x` is outside of the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Label the connected components of the grid with their number of
+    /// components. Orthogonal neighbours are in the same component when `linked`
+    /// accepts a cell, its neighbour and the direction from the first to the
+    /// second, which is only asked for `Right` and `Down`. Components are
+    /// numbered from 0 in the order of their first cell, row by row.
+    pub fn label_components(
+        &self,
+        mut linked: impl FnMut(&T, &T, Dir4) -> bool,
+    ) -> (Grid<usize>, usize) {
+        let mut sets = UnionFind::new(self.cells.len());
+        for pos in self.positions() {
+            for dir in [Dir4::Right, Dir4::Down] {
+                if let Some(next) = self.idx(pos + dir) {
+                    if linked(&self[pos], &self.cells[next], dir) {
+                        sets.union(self.idx(pos).unwrap(), next);
+                    }
+                }
+            }
+        }
+        let labels = Grid::new(self.width, self.height, sets.labels());
+        (labels, sets.count())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Point) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Grid {}x{}", self.width, self.height)?;
+        for row in self.rows() {
+            writeln!(f, "{row:?}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::Grid;
+    use crate::geom::{Dir4, Point};
+
+    const INPUT: &str = "ab.
+        .c.";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse_with(0, INPUT, "a letter or `.`", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&'c'));
+        assert_eq!(grid[Point::new(0, 0)], 'a');
+        let grid: Grid<u8> = Grid::parse(0, "12\n34", "a digit").unwrap();
+        assert_eq!(grid[Point::new(1, 1)], 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::<u8>::parse(0, "12\n3x", "a digit").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        let err = Grid::<u8>::parse(0, "12\n345", "a digit").unwrap_err();
+        assert_eq!(err.expected, "a row of 2 cells");
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = Grid::parse_with(0, INPUT, "", Some).unwrap();
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse_with(0, INPUT, "", Some).unwrap();
+        let corner: Vec<_> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![(Point::new(1, 0), &'b'), (Point::new(0, 1), &'.')]
+        );
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse_with(0, INPUT, "", Some).unwrap();
+        assert_eq!(grid.row(1).collect::<String>(), ".c.");
+        assert_eq!(grid.column(1).collect::<String>(), "bc");
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.column(usize::MAX).count(), 0);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.find(|&c| c == 'c'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_label_components() {
+        let grid: Grid<char> = Grid::parse_with(0, "aab\nbab\nbbb", "", Some).unwrap();
+        let (labels, count) = grid.label_components(|a, b, _| a == b);
+        assert_eq!(count, 2);
+        assert_eq!(
+            labels.rows().collect::<Vec<_>>(),
+            [[0, 0, 1], [1, 0, 1], [1, 1, 1]]
+        );
+        let (labels, count) = grid.label_components(|a, b, dir| a == b && dir == Dir4::Right);
+        assert_eq!(count, 6);
+        assert_eq!(labels[Point::new(1, 2)], labels[Point::new(2, 2)]);
+        assert_ne!(labels[Point::new(0, 1)], labels[Point::new(0, 2)]);
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fs, path::Path};
+
+use crate::geom::Point;
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+    pub const GREEN: Rgb = Rgb(40, 200, 70);
+    pub const BLUE: Rgb = Rgb(40, 70, 200);
+    pub const YELLOW: Rgb = Rgb(240, 210, 50);
+    pub const NAVY: Rgb = Rgb(20, 25, 60);
+}
+
+/// An RGB picture, for grids too large to be read in a terminal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub pixels: Grid<Rgb>,
+}
+
+impl Image {
+    /// Draw every cell of `grid` as a square of `scale` pixels of one colour
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        scale: usize,
+        mut color: impl FnMut(Point, &T) -> Rgb,
+    ) -> Self {
+        Image::from_grid_scaled(grid, scale, |pos, cell, _| color(pos, cell))
+    }
+
+    /// Draw every cell of `grid` as a square of `scale` pixels, `color` getting
+    /// the cell and the position of the pixel inside its square, so that cells
+    /// can be drawn as small sprites
+    pub fn from_grid_scaled<T>(
+        grid: &Grid<T>,
+        scale: usize,
+        mut color: impl FnMut(Point, &T, Point) -> Rgb,
+    ) -> Self {
+        let (width, height) = (grid.width() * scale, grid.height() * scale);
+        let scale = scale as i64;
+        let pixels = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| Point::new(x, y)))
+            .map(|pixel| {
+                let pos = Point::new(pixel.x / scale, pixel.y / scale);
+                let inner = Point::new(pixel.x % scale, pixel.y % scale);
+                color(pos, &grid[pos], inner)
+            })
+            .collect();
+        Image {
+            pixels: Grid::new(width, height, pixels),
+        }
+    }
+
+    /// Binary PPM, the simplest format most image viewers open
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut res = format!(
+            "P6\n{} {}\n255\n",
+            self.pixels.width(),
+            self.pixels.height()
+        )
+        .into_bytes();
+        for (_, &Rgb(r, g, b)) in self.pixels.iter() {
+            res.extend([r, g, b]);
+        }
+        res
+    }
+
+    pub fn write_ppm(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_ppm()).map_err(|e| format!("cannot write {}: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{Image, Rgb};
+    use crate::geom::Point;
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_from_grid() {
+        let grid: Grid<char> = Grid::parse_with(0, "#.\n.#", "", Some).unwrap();
+        let image = Image::from_grid(
+            &grid,
+            2,
+            |_, &c| if c == '#' { Rgb::WHITE } else { Rgb::BLACK },
+        );
+        assert_eq!((image.pixels.width(), image.pixels.height()), (4, 4));
+        assert_eq!(image.pixels[Point::new(1, 1)], Rgb::WHITE);
+        assert_eq!(image.pixels[Point::new(2, 1)], Rgb::BLACK);
+        assert_eq!(image.pixels[Point::new(3, 3)], Rgb::WHITE);
+        // a cross in the middle of each cell
+        let image = Image::from_grid_scaled(&grid, 3, |_, _, inner| {
+            if inner.x == 1 || inner.y == 1 {
+                Rgb::RED
+            } else {
+                Rgb::BLACK
+            }
+        });
+        assert_eq!(image.pixels[Point::new(4, 0)], Rgb::RED);
+        assert_eq!(image.pixels[Point::new(5, 0)], Rgb::BLACK);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let grid = Grid::new(2, 1, vec![Rgb(1, 2, 3), Rgb(4, 5, 6)]);
+        let image = Image::from_grid(&grid, 1, |_, &c| c);
+        let mut expected = b"P6\n2 1\n255\n".to_vec();
+        expected.extend([1, 2, 3, 4, 5, 6]);
+        assert_eq!(image.to_ppm(), expected);
+    }
+}
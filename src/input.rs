@@ -0,0 +1,100 @@
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding the directory of the personal inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory containing the `<year>/dayN.txt` inputs, `$AOC_INPUT_DIR` when set,
+/// otherwise the `input` directory of the crate whatever the current directory is
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("input"),
+    }
+}
+
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+/// Read the personal input of a day, `None` when it has not been downloaded
+pub fn load(year: u32, day: u32) -> io::Result<Option<String>> {
+    match fs::read_to_string(input_path(year, day)) {
+        Ok(input) => Ok(Some(input)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Read an input file, `-` reads the standard input instead
+pub fn read(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Report a test skipped for lack of personal data, the test then returns early
+/// since the harness has no skipped outcome
+pub fn skip(reason: impl Display) {
+    eprintln!("skipped: {reason}");
+}
+
+/// Load the personal input of a day in a test, or return from the test with a
+/// skip message when the input is missing (e.g. on a fresh clone)
+#[macro_export]
+macro_rules! input_or_skip {
+    ($year:expr, $day:expr) => {
+        match $crate::input::load($year, $day) {
+            Ok(Some(input)) => input,
+            Ok(None) => {
+                $crate::input::skip(format_args!(
+                    "{} not found, download it or set {}",
+                    $crate::input::input_path($year, $day).display(),
+                    $crate::input::INPUT_DIR_VAR
+                ));
+                return;
+            }
+            Err(e) => panic!(
+                "cannot read {}: {e}",
+                $crate::input::input_path($year, $day).display()
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{input_path, load};
+
+    #[test]
+    fn test_input_path() {
+        assert!(input_path(2023, 1).ends_with("2023/day1.txt"));
+    }
+
+    #[test]
+    fn test_missing_input() {
+        assert_eq!(load(1999, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_input_or_skip() {
+        let mut reached = false;
+        let mut run = || {
+            let _input = input_or_skip!(1999, 1);
+            reached = true;
+        };
+        run();
+        assert!(!reached);
+    }
+}
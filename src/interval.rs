@@ -0,0 +1,275 @@
+use std::fmt::Debug;
+
+/// Half-open range of integers `start..end`, empty when `start >= end`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// The `len` integers from `start`, the way the puzzles usually give ranges
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Interval::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start)
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Common part of both intervals, possibly empty
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Parts of `self` before and after `other`, either possibly empty
+    pub fn difference(&self, other: &Interval) -> (Interval, Interval) {
+        (
+            Interval::new(self.start, self.end.min(other.start)),
+            Interval::new(self.start.max(other.end), self.end),
+        )
+    }
+
+    pub fn shift(&self, delta: i64) -> Interval {
+        Interval::new(self.start + delta, self.end + delta)
+    }
+}
+
+impl Debug for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as sorted, disjoint and non adjacent intervals, so
+/// that touching or overlapping intervals are merged as soon as they are added.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // Intervals before `first` end before the new one, those from `last`
+        // start after it, every one in between is merged into it.
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last].iter().fold(interval, |acc, i| {
+            Interval::new(acc.start.min(i.start), acc.end.max(i.end))
+        });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut res = Vec::with_capacity(self.intervals.len() + 1);
+        for i in &self.intervals {
+            let (before, after) = i.difference(&interval);
+            res.extend([before, after].into_iter().filter(|i| !i.is_empty()));
+        }
+        self.intervals = res;
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut res = self.clone();
+        for &interval in &other.intervals {
+            res.insert(interval);
+        }
+        res
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut res = self.clone();
+        for &interval in &other.intervals {
+            res.remove(interval);
+        }
+        res
+    }
+
+    pub fn shift(&self, delta: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self.intervals.iter().map(|i| i.shift(delta)).collect(),
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut res = IntervalSet::new();
+        for interval in iter {
+            res.insert(interval);
+        }
+        res
+    }
+}
+
+/// Piecewise shift of integers: a value inside one of the source intervals is
+/// moved by its delta, any other value is left unchanged. When sources overlap
+/// the first one added wins.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RangeMap {
+    ranges: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        RangeMap::default()
+    }
+
+    pub fn insert(&mut self, source: Interval, delta: i64) {
+        self.ranges.push((source, delta));
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        self.ranges
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(_, delta)| value + delta)
+    }
+
+    /// Image of every value of `set`, splitting its intervals where they cross
+    /// the bounds of a source interval
+    pub fn apply(&self, set: &IntervalSet) -> IntervalSet {
+        let mut remaining = set.clone();
+        let mut res = IntervalSet::new();
+        for &(source, delta) in &self.ranges {
+            let source = IntervalSet::from_iter([source]);
+            res = res.union(&remaining.intersection(&source).shift(delta));
+            remaining = remaining.difference(&source);
+        }
+        res.union(&remaining)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{Interval, IntervalSet, RangeMap};
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(s, e)| Interval::new(s, e))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::with_len(2, 5);
+        assert_eq!(a, Interval::new(2, 7));
+        assert_eq!(a.len(), 5);
+        assert!(a.contains(2) && a.contains(6) && !a.contains(7));
+        assert_eq!(a.intersection(&Interval::new(5, 10)), Interval::new(5, 7));
+        assert!(!a.overlaps(&Interval::new(7, 10)));
+        assert_eq!(
+            a.difference(&Interval::new(3, 5)),
+            (Interval::new(2, 3), Interval::new(5, 7))
+        );
+        let (before, after) = a.difference(&Interval::new(0, 10));
+        assert!(before.is_empty() && after.is_empty());
+        assert_eq!(Interval::new(5, 2).len(), 0);
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut s = set(&[(0, 2), (5, 7), (10, 12)]);
+        assert_eq!(s.iter().count(), 3);
+        // touching intervals are merged too
+        s.insert(Interval::new(2, 5));
+        assert_eq!(s, set(&[(0, 7), (10, 12)]));
+        s.insert(Interval::new(-3, 11));
+        assert_eq!(s, set(&[(-3, 12)]));
+        s.insert(Interval::new(20, 20));
+        assert_eq!(s.len(), 15);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert!(a.contains(0) && a.contains(29) && !a.contains(10) && !a.contains(30));
+        assert_eq!((a.min(), a.max()), (Some(0), Some(29)));
+        assert_eq!(a.shift(-5), set(&[(-5, 5), (15, 25)]));
+    }
+
+    #[test]
+    fn test_range_map() {
+        // seed-to-soil map of the day 5 example
+        let mut map = RangeMap::new();
+        map.insert(Interval::with_len(98, 2), 50 - 98);
+        map.insert(Interval::with_len(50, 48), 52 - 50);
+        assert_eq!(
+            [79, 14, 55, 13, 99].map(|x| map.get(x)),
+            [81, 14, 57, 13, 51]
+        );
+        // 45..50 is unchanged, 50..60 becomes 52..62 and 98..100 fills the gap
+        let seeds = set(&[(45, 60), (95, 101)]);
+        assert_eq!(map.apply(&seeds), set(&[(45, 62), (97, 101)]));
+        assert_eq!(map.apply(&set(&[(0, 10)])), set(&[(0, 10)]));
+    }
+}
@@ -1,16 +1,35 @@
 use aoc_runner_derive::aoc_lib;
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+// lets `#[derive(LineFormat)]` refer to `::advent_of_code::parse` inside this crate
+extern crate self as advent_of_code;
+
+pub mod answers;
+pub mod bench;
+pub mod budget;
+pub mod cycle;
+pub mod example;
+pub mod explain;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod polygon;
+pub mod property;
+pub mod render;
+pub mod runner;
+pub mod trace;
+pub mod union_find;
+pub mod y2023;
+
+use runner::Year;
+
+/// Every event, sorted by year. `aoc_lib!` only handles a single year so the
+/// solutions of other years are registered with `solution!` instead of `#[aoc]`.
+pub const YEARS: &[Year] = &[y2023::YEAR];
 
 aoc_lib! { year = 2023 }
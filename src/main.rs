@@ -1,3 +1,566 @@
-use aoc_runner_derive::aoc_main;
+use std::{
+    collections::HashMap,
+    env,
+    io::{self, IsTerminal},
+    ops::RangeInclusive,
+    path::Path,
+    process::ExitCode,
+};
 
-aoc_main! { lib = advent_of_code }
+use advent_of_code::{
+    answers::{self, Answers, ECheck},
+    bench,
+    budget::{self, Budgets},
+    input,
+    render::EStyle,
+    runner::{self, ERunError, Solution, Year},
+    trace, YEARS,
+};
+
+const USAGE: &str = "Usage: advent-of-code [COMMAND] [OPTIONS]
+
+Commands:
+  run <DAYS>        Run the given days, e.g. `5`, `3..7` or `all`
+  all               Run every day of the year (default)
+  bench <DAYS>      Run the given days repeatedly and report the min, median and p95 timings
+  check [DAYS]      Compare the answers of the given days with answers.toml
+  diff <OLD> <NEW>  Compare the median timings of two bench reports
+  render <DAY>      Draw the input of a grid day with what its solution found
+
+Options:
+  -y, --year <YEAR>       Event to run [default: the latest one]
+  -p, --part <PART>       Only run part 1 or part 2
+  -i, --input <PATH>      Input file instead of $AOC_INPUT_DIR/<year>/dayN.txt, `-` for stdin
+  -n, --iterations <N>    Number of runs of each part for bench [default: 100]
+  -r, --report <PATH>     Write the bench timings to a .json or .csv file
+  -b, --budget            Also check the time of each part against budgets.toml
+  -t, --trace <PATH>      Write the events traced by `run` to a text or .jsonl file (`--features trace`)
+  -e, --explain           Print how `run` got each answer from the input
+      --plain             Render without colours, the default when not printing to a terminal
+      --image <PATH>      Render to a .ppm image instead of text
+  -h, --help              Print this help";
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum ECommand {
+    Run,
+    Bench,
+    Check,
+    Diff,
+    Render,
+}
+
+struct Args {
+    command: ECommand,
+    year: &'static Year,
+    days: RangeInclusive<u32>,
+    part: Option<u32>,
+    input: Option<String>,
+    iterations: u32,
+    report: Option<String>,
+    budget: bool,
+    explain: bool,
+    plain: bool,
+    image: Option<String>,
+    trace: Option<String>,
+    /// Old and new reports of `diff`
+    reports: Option<(String, String)>,
+}
+
+fn parse_days(days: &str) -> Result<RangeInclusive<u32>, String> {
+    if days == "all" {
+        return Ok(1..=25);
+    }
+    let parse_day = |day: &str| {
+        day.parse::<u32>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| format!("invalid day `{day}`, expected a number from 1 to 25"))
+    };
+    match days.split_once("..") {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to.trim_start_matches('='))?);
+            if from > to {
+                return Err(format!(
+                    "invalid days `{days}`, the first day is after the last"
+                ));
+            }
+            Ok(from..=to)
+        }
+        None => {
+            let day = parse_day(days)?;
+            Ok(day..=day)
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut res = Args {
+        command: ECommand::Run,
+        year: YEARS.last().unwrap(),
+        days: 1..=25,
+        part: None,
+        input: None,
+        iterations: 100,
+        report: None,
+        budget: false,
+        explain: false,
+        plain: false,
+        image: None,
+        trace: None,
+        reports: None,
+    };
+    let mut command = None;
+    let mut values = vec![];
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("missing value for `{name}`"));
+        match arg.as_str() {
+            "-h" | "--help" => return Err(String::new()),
+            "-p" | "--part" => {
+                let part = value(&arg)?;
+                res.part = Some(match part.as_str() {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => return Err(format!("invalid part `{part}`, expected 1 or 2")),
+                });
+            }
+            "-y" | "--year" => {
+                let year = value(&arg)?;
+                res.year = YEARS
+                    .iter()
+                    .find(|y| y.year.to_string() == year)
+                    .ok_or_else(|| {
+                        let years: Vec<_> = YEARS.iter().map(|y| y.year.to_string()).collect();
+                        format!(
+                            "unknown year `{year}`, expected one of {}",
+                            years.join(", ")
+                        )
+                    })?;
+            }
+            "-i" | "--input" => res.input = Some(value(&arg)?),
+            "-r" | "--report" => res.report = Some(value(&arg)?),
+            "-b" | "--budget" => res.budget = true,
+            "-e" | "--explain" => res.explain = true,
+            "--plain" => res.plain = true,
+            "--image" => res.image = Some(value(&arg)?),
+            "-t" | "--trace" => res.trace = Some(value(&arg)?),
+            "-n" | "--iterations" => {
+                let iterations = value(&arg)?;
+                res.iterations = iterations
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("invalid number of iterations `{iterations}`"))?;
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{arg}`"))
+            }
+            _ if command.is_none() => {
+                command = Some(arg);
+            }
+            _ => values.push(arg),
+        }
+    }
+    let mut values = values.into_iter();
+    let first = values.next();
+    let days = || first.as_deref().map(parse_days).transpose();
+    match command.as_deref() {
+        None | Some("all") => {
+            if let Some(arg) = first {
+                return Err(format!("unexpected argument `{arg}`"));
+            }
+        }
+        Some("run") => {
+            res.days = days()?.ok_or("`run` needs the days to run, e.g. `run 5`")?;
+        }
+        Some("bench") => {
+            res.command = ECommand::Bench;
+            res.days = days()?.ok_or("`bench` needs the days to run, e.g. `bench 5`")?;
+        }
+        Some("check") => {
+            res.command = ECommand::Check;
+            res.days = days()?.unwrap_or(1..=25);
+        }
+        Some("diff") => {
+            res.command = ECommand::Diff;
+            let reports = first.clone().zip(values.next());
+            res.reports =
+                Some(reports.ok_or("`diff` needs two reports, e.g. `diff old.json new.json`")?);
+        }
+        Some("render") => {
+            res.command = ECommand::Render;
+            res.days = days()?
+                .filter(|days| days.start() == days.end())
+                .ok_or("`render` needs a single day, e.g. `render 10`")?;
+        }
+        Some(command) => return Err(format!("unknown command `{command}`")),
+    }
+    if let Some(arg) = values.next() {
+        return Err(format!("unexpected argument `{arg}`"));
+    }
+    if res.report.is_some() && res.command != ECommand::Bench {
+        return Err("`--report` can only be used with `bench`".to_string());
+    }
+    if res.budget && res.command != ECommand::Check {
+        return Err("`--budget` can only be used with `check`".to_string());
+    }
+    if res.explain && res.command != ECommand::Run {
+        return Err("`--explain` can only be used with `run`".to_string());
+    }
+    if (res.plain || res.image.is_some()) && res.command != ECommand::Render {
+        return Err("`--plain` and `--image` can only be used with `render`".to_string());
+    }
+    if res.trace.is_some() {
+        if res.command != ECommand::Run || res.days.start() != res.days.end() {
+            return Err("`--trace` can only be used when running a single day".to_string());
+        }
+        if !trace::ENABLED {
+            return Err("`--trace` needs a build with `--features trace`".to_string());
+        }
+    }
+    if res.input.is_some() && res.days.start() != res.days.end() {
+        return Err("`--input` can only be used when running a single day".to_string());
+    }
+    Ok(res)
+}
+
+/// Inputs are read once per day so that both parts can share the standard input
+struct Inputs {
+    year: u32,
+    path: Option<String>,
+    cache: HashMap<u32, Result<String, String>>,
+}
+
+impl Inputs {
+    fn get(&mut self, day: u32) -> &Result<String, String> {
+        self.cache.entry(day).or_insert_with(|| {
+            let path = match &self.path {
+                Some(path) => path.clone(),
+                None => input::input_path(self.year, day).display().to_string(),
+            };
+            input::read(&path).map_err(|e| format!("cannot read `{path}`: {e}"))
+        })
+    }
+}
+
+fn check(year: u32, solutions: &[&Solution], inputs: &mut Inputs, budget: bool) -> ExitCode {
+    let loaded = Answers::load(answers::answers_path())
+        .and_then(|answers| Ok((answers, Budgets::load(budget::budgets_path())?)));
+    let (answers, budgets) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if budget && cfg!(debug_assertions) {
+        eprintln!("warning: budgets are meant for release builds, run with `--release`");
+    }
+    let (mut pass, mut fail, mut missing, mut over_budget) = (0, 0, 0, 0);
+    for solution in solutions {
+        let input = inputs.get(solution.day).as_ref().ok();
+        let res = answers::check(&answers, year, solution, input.map(|x| x.as_str()));
+        match &res {
+            ECheck::Pass => pass += 1,
+            ECheck::MissingAnswer { .. } | ECheck::MissingInput => missing += 1,
+            ECheck::Fail { .. } | ECheck::Error(_) => fail += 1,
+        }
+        print!("Day {} - Part {}: {res}", solution.day, solution.part);
+        if let (true, Some(input), false) = (budget, input, matches!(res, ECheck::Error(_))) {
+            match budget::check(&budgets, year, solution, input) {
+                Ok(res) => {
+                    over_budget += res.is_failure() as u32;
+                    print!(", {res}");
+                }
+                Err(e) => print!(", {e}"),
+            }
+        }
+        match (&res, input) {
+            (ECheck::MissingAnswer { .. }, Some(input)) => println!(
+                " (add it under [{year}.day{}.{}] in {})",
+                solution.day,
+                answers::fingerprint(input),
+                answers::answers_path().display()
+            ),
+            _ => println!(),
+        }
+    }
+    print!("\n{pass} passed, {fail} failed, {missing} missing");
+    if budget {
+        print!(", {over_budget} over budget");
+    }
+    println!();
+    if fail > 0 || over_budget > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn diff(old: &str, new: &str) -> ExitCode {
+    let reports = bench::read_report(Path::new(old))
+        .and_then(|old| Ok((old, bench::read_report(Path::new(new))?)));
+    match reports {
+        Ok((old, new)) => {
+            for line in bench::diff(&old, &new) {
+                println!("{line}\n");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Breakdown of the answer of `solution`, or a note when it has none
+fn explain(year: &Year, solution: &Solution, input: &str) -> Result<String, ERunError> {
+    match year.explain(solution.day, solution.part) {
+        Some(explain) => explain(input)
+            .map(|breakdown| breakdown.to_string().trim_end().to_string())
+            .map_err(|e| ERunError::Generating(Box::new(e))),
+        None => Ok("no explanation for this part".to_string()),
+    }
+}
+
+fn render(year: &Year, day: u32, inputs: &mut Inputs, args: &Args) -> ExitCode {
+    let input = match inputs.get(day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let res = match &args.image {
+        Some(path) => match year.image(day) {
+            Some(draw) => draw(input)
+                .map_err(|e| e.to_string())
+                .and_then(|image| image.write_ppm(Path::new(path)))
+                .map(|()| format!("Image written to {path}\n")),
+            None => Err(format!("day {day} of {} has no image", year.year)),
+        },
+        None => match year.render(day) {
+            Some(render) => {
+                let style = if args.plain || !io::stdout().is_terminal() {
+                    EStyle::Plain
+                } else {
+                    EStyle::Ansi
+                };
+                render(input, style).map_err(|e| e.to_string())
+            }
+            None => Err(format!("day {day} of {} has no render", year.year)),
+        },
+    };
+    match res {
+        Ok(text) => {
+            print!("{text}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            if e.is_empty() {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    if let Some((old, new)) = &args.reports {
+        return diff(old, new);
+    }
+    let year = args.year.year;
+    let mut inputs = Inputs {
+        year,
+        path: args.input.clone(),
+        cache: HashMap::new(),
+    };
+    if args.command == ECommand::Render {
+        return render(args.year, *args.days.start(), &mut inputs, &args);
+    }
+    let solutions: Vec<&Solution> = args
+        .year
+        .solutions
+        .iter()
+        .filter(|s| args.days.contains(&s.day) && args.part.is_none_or(|p| p == s.part))
+        .collect();
+    if solutions.is_empty() {
+        eprintln!("error: no solution for days {:?} of {year}", args.days);
+        return ExitCode::FAILURE;
+    }
+
+    println!("Advent of code {year}");
+    if args.command == ECommand::Check {
+        return check(year, &solutions, &mut inputs, args.budget);
+    }
+    let mut failures = 0;
+    let mut measures = vec![];
+    let mut traces = String::new();
+    for solution in solutions {
+        let input = match inputs.get(solution.day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {} - Part {}: {e}", solution.day, solution.part);
+                failures += 1;
+                continue;
+            }
+        };
+        let res = match args.command {
+            ECommand::Bench => {
+                bench::bench(year, solution, input, args.iterations).map(|(answer, measure)| {
+                    let res = format!("{measure}\n\tanswer: {}", answer.answer);
+                    measures.push(measure);
+                    res
+                })
+            }
+            _ => {
+                let (res, events) = trace::capture(|| runner::run(solution, input));
+                if let Some(path) = &args.trace {
+                    let format = trace::EFormat::from_path(Path::new(path));
+                    let (day, part) = (solution.day, solution.part);
+                    traces.push_str(&trace::format_events(year, day, part, &events, format));
+                }
+                res.and_then(|answer| {
+                    if args.explain {
+                        explain(args.year, solution, input).map(|e| format!("{answer}\n{e}"))
+                    } else {
+                        Ok(answer.to_string())
+                    }
+                })
+            }
+        };
+        match res {
+            Ok(res) => println!("{res}\n"),
+            Err(e) => {
+                eprintln!("Day {} - Part {}: {e}", solution.day, solution.part);
+                failures += 1;
+            }
+        }
+    }
+    if let Some(report) = &args.report {
+        match bench::write_report(Path::new(report), &measures) {
+            Ok(()) => println!("Report written to {report}"),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failures += 1;
+            }
+        }
+    }
+    if let Some(path) = &args.trace {
+        match std::fs::write(path, traces) {
+            Ok(()) => println!("Trace written to {path}"),
+            Err(e) => {
+                eprintln!("error: cannot write {path}: {e}");
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{parse_args, trace, Args, ECommand};
+
+    fn parse(args: &str) -> Args {
+        match parse_args(args.split_whitespace().map(String::from)) {
+            Ok(args) => args,
+            Err(e) => panic!("`{args}` failed: {e}"),
+        }
+    }
+
+    fn error(args: &str) -> String {
+        match parse_args(args.split_whitespace().map(String::from)) {
+            Ok(_) => panic!("`{args}` was accepted"),
+            Err(e) => e,
+        }
+    }
+
+    #[test]
+    fn test_commands() {
+        let args = parse("");
+        assert_eq!((args.command, args.days), (ECommand::Run, 1..=25));
+        assert_eq!(parse("all").days, 1..=25);
+        let args = parse("run 3..7 -p 2 -y 2023");
+        assert_eq!(
+            (args.command, args.days, args.part),
+            (ECommand::Run, 3..=7, Some(2))
+        );
+        assert_eq!(args.year.year, 2023);
+        let args = parse("bench 5 -n 10 -r new.json");
+        assert_eq!((args.command, args.days), (ECommand::Bench, 5..=5));
+        assert_eq!(
+            (args.iterations, args.report),
+            (10, Some("new.json".to_string()))
+        );
+        let args = parse("check --budget");
+        assert_eq!(
+            (args.command, args.days, args.budget),
+            (ECommand::Check, 1..=25, true)
+        );
+        let args = parse("diff old.json new.json");
+        assert_eq!(args.command, ECommand::Diff);
+        assert_eq!(
+            args.reports,
+            Some(("old.json".to_string(), "new.json".to_string()))
+        );
+        let args = parse("render 10 --plain --image loop.ppm");
+        assert_eq!(
+            (args.command, args.days, args.plain),
+            (ECommand::Render, 10..=10, true)
+        );
+        assert_eq!(args.image, Some("loop.ppm".to_string()));
+        let args = parse("run 4 -e -i -");
+        assert_eq!((args.explain, args.input), (true, Some("-".to_string())));
+        assert_eq!(error("--help"), "");
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert_eq!(error("run"), "`run` needs the days to run, e.g. `run 5`");
+        assert_eq!(error("run 5 -x"), "unknown option `-x`");
+        assert_eq!(error("fly 5"), "unknown command `fly`");
+        assert_eq!(error("run 5 6"), "unexpected argument `6`");
+        assert_eq!(error("all 5"), "unexpected argument `5`");
+        assert_eq!(error("run 5 -p 3"), "invalid part `3`, expected 1 or 2");
+        assert_eq!(
+            error("run 7..3"),
+            "invalid days `7..3`, the first day is after the last"
+        );
+        assert_eq!(error("bench 5 -n 0"), "invalid number of iterations `0`");
+        assert!(error("run 5 -y 1999").starts_with("unknown year `1999`"));
+        assert!(error("diff old.json").starts_with("`diff` needs two reports"));
+        assert!(error("render 3..5").starts_with("`render` needs a single day"));
+        assert!(error("run 5 --budget").contains("only be used with `check`"));
+        assert!(error("check --report a.json").contains("only be used with `bench`"));
+        assert!(error("check 5 --explain").contains("only be used with `run`"));
+        assert!(error("run 5 --plain").contains("only be used with `render`"));
+        assert!(error("run 1..3 -i input.txt").contains("a single day"));
+        assert!(error("run 1..3 --trace t.txt").contains("a single day"));
+        let trace = parse_args(["run", "8", "-t", "t.jsonl"].map(String::from).into_iter());
+        assert_eq!(trace.is_ok(), trace::ENABLED);
+    }
+
+    #[test]
+    fn test_missing_values() {
+        for option in ["-p", "-y", "-i", "-n", "-r", "-t", "--image"] {
+            assert_eq!(
+                error(&format!("run 5 {option}")),
+                format!("missing value for `{option}`")
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` when it does not fit in a `u64`. Dividing
+/// before multiplying only overflows when the result itself does.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of every value, 1 for no value
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`, with `g >= 0`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` such that `a * x = 1 (mod modulus)`, `None` when `a` and
+/// `modulus` are not coprime or when `modulus` is 0. Modulo 1 every number is
+/// congruent to 0, so the inverse of any `a` is 0.
+pub fn mod_inverse(a: i64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let modulus = modulus as i128;
+    let (g, x, _) = extended_gcd((a as i128).rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus) as u64)
+}
+
+/// Solve `x = residue (mod modulus)` for every congruence, returning the smallest
+/// non negative `x` with the combined modulus, the lcm of the moduli. Moduli do
+/// not need to be coprime: `None` when the congruences contradict each other,
+/// when a modulus is 0 or when the combined modulus does not fit in a `u64`.
+pub fn crt(congruences: &[(i64, u64)]) -> Option<(u64, u64)> {
+    let mut residue: i128 = 0;
+    let mut modulus: i128 = 1;
+    for &(r, m) in congruences {
+        if m == 0 {
+            return None;
+        }
+        let m = m as i128;
+        let r = (r as i128).rem_euclid(m);
+        // residue + modulus * k = r (mod m)
+        let (g, inverse, _) = extended_gcd(modulus, m);
+        let difference = r - residue;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        let combined = u64::try_from(modulus.checked_mul(step)?).ok()? as i128;
+        // both factors are below 2^64, their product only fits in a u128
+        let k = (difference / g).rem_euclid(step) as u128 * inverse.rem_euclid(step) as u128;
+        let k = (k % step as u128) as i128;
+        residue = (residue + modulus * k).rem_euclid(combined);
+        modulus = combined;
+    }
+    Some((residue as u64, modulus as u64))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{crt, extended_gcd, gcd, lcm, lcm_all, mod_inverse};
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        // 2^40 * 3 and 2^40 * 5: the product overflows but not the lcm
+        assert_eq!(lcm(3 << 40, 5 << 40), Some(15 << 40));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([]), Some(1));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (35, 64)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(
+                g,
+                gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64) as i128
+            );
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(1, 1), Some(0));
+        assert_eq!(mod_inverse(-7, 1), Some(0));
+        assert_eq!(mod_inverse(0, 1), Some(0));
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_inverse(1, 0), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[(0, 12), (0, 18)]), Some((0, 36)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (5, 1)]), Some((2, 3)));
+        assert_eq!(crt(&[(2, 3), (0, 0)]), None);
+        assert_eq!(crt(&[(1, u64::MAX), (0, u64::MAX - 1)]), None);
+        let big = (1 << 61) - 1;
+        assert_eq!(crt(&[(5, big), (3, 8)]), Some((2 * big + 5, 8 * big)));
+        assert_eq!(crt(&[(3, 8), (5, big)]), Some((2 * big + 5, 8 * big)));
+    }
+}
@@ -0,0 +1,176 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Storage of the values computed by a `Memo`
+pub trait Cache<K, V> {
+    fn get(&self, key: &K) -> Option<V>;
+    fn insert(&mut self, key: K, value: V);
+}
+
+/// Cache of a function over a small `N`-dimensional integer state, e.g. a pair
+/// of indices, stored in a flat table. Keys outside of the dimensions panic.
+#[derive(Debug, Clone)]
+pub struct DenseCache<V, const N: usize> {
+    dims: [usize; N],
+    values: Vec<Option<V>>,
+}
+
+impl<V: Clone, const N: usize> DenseCache<V, N> {
+    pub fn new(dims: [usize; N]) -> Self {
+        DenseCache {
+            dims,
+            values: vec![None; dims.iter().product()],
+        }
+    }
+
+    fn index(&self, key: &[usize; N]) -> usize {
+        key.iter().zip(&self.dims).fold(0, |acc, (&k, &dim)| {
+            assert!(
+                k < dim,
+                "{key:?} is outside of the cache of size {:?}",
+                self.dims
+            );
+            acc * dim + k
+        })
+    }
+}
+
+impl<V: Clone, const N: usize> Cache<[usize; N], V> for DenseCache<V, N> {
+    fn get(&self, key: &[usize; N]) -> Option<V> {
+        self.values[self.index(key)].clone()
+    }
+
+    fn insert(&mut self, key: [usize; N], value: V) {
+        let i = self.index(&key);
+        self.values[i] = Some(value);
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Cache<K, V> for HashMap<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        HashMap::get(self, key).cloned()
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+}
+
+/// A recursive function whose results are cached. The function gets the memoized
+/// function itself to recurse through, so no cache has to be passed around:
+///
+/// ```
+/// use advent_of_code::memo::Memo;
+///
+/// let mut fibonacci = Memo::dense([91], |fibonacci, [n]| match n {
+///     0 | 1 => n as u64,
+///     _ => fibonacci([n - 1]) + fibonacci([n - 2]),
+/// });
+/// assert_eq!(fibonacci.get([90]), 2880067194370816120);
+/// ```
+pub struct Memo<K, V, C, F> {
+    cache: C,
+    f: F,
+    _marker: std::marker::PhantomData<fn(K) -> V>,
+}
+
+impl<K, V, C, F> Memo<K, V, C, F>
+where
+    K: Clone,
+    V: Clone,
+    C: Cache<K, V>,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    pub fn new(cache: C, f: F) -> Self {
+        Memo {
+            cache,
+            f,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn get(&mut self, key: K) -> V {
+        call(&mut self.cache, &self.f, key)
+    }
+}
+
+impl<V, F, const N: usize> Memo<[usize; N], V, DenseCache<V, N>, F>
+where
+    V: Clone,
+    F: Fn(&mut dyn FnMut([usize; N]) -> V, [usize; N]) -> V,
+{
+    /// Memoize `f` over the keys below `dims`, e.g. `[springs.len(), rules.len()]`
+    pub fn dense(dims: [usize; N], f: F) -> Self {
+        Memo::new(DenseCache::new(dims), f)
+    }
+}
+
+impl<K, V, F> Memo<K, V, HashMap<K, V>, F>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    /// Memoize `f` over any hashable key
+    pub fn hashed(f: F) -> Self {
+        Memo::new(HashMap::new(), f)
+    }
+}
+
+fn call<K, V, C, F>(cache: &mut C, f: &F, key: K) -> V
+where
+    K: Clone,
+    V: Clone,
+    C: Cache<K, V>,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    if let Some(value) = cache.get(&key) {
+        return value;
+    }
+    let value = f(&mut |key| call(cache, f, key), key.clone());
+    cache.insert(key, value.clone());
+    value
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::cell::Cell;
+
+    use super::Memo;
+
+    #[test]
+    fn test_dense() {
+        let calls = Cell::new(0);
+        // paths from (0, 0) to (x, y) moving right or down
+        let mut paths = Memo::dense([17, 17], |paths, [x, y]| {
+            calls.set(calls.get() + 1);
+            match (x, y) {
+                (0, _) | (_, 0) => 1u64,
+                _ => paths([x - 1, y]) + paths([x, y - 1]),
+            }
+        });
+        assert_eq!(paths.get([16, 16]), 601080390);
+        assert_eq!(calls.get(), 17 * 17 - 1);
+        assert_eq!(paths.get([3, 2]), 10);
+        assert_eq!(calls.get(), 17 * 17 - 1);
+    }
+
+    #[test]
+    fn test_hashed() {
+        // number of ways to split `n` into parts of at most `max`
+        let mut partitions = Memo::hashed(|partitions, (n, max): (i64, i64)| match (n, max) {
+            (0, _) => 1u64,
+            (n, _) if n < 0 => 0,
+            (_, 0) => 0,
+            _ => partitions((n - max, max)) + partitions((n, max - 1)),
+        });
+        assert_eq!(partitions.get((5, 5)), 7);
+        assert_eq!(partitions.get((100, 100)), 190569292);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the cache")]
+    fn test_dense_out_of_bounds() {
+        let mut memo = Memo::dense([2], |_, [n]| n);
+        memo.get([2]);
+    }
+}
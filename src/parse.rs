@@ -0,0 +1,536 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    error::Error,
+    fmt::Display,
+    hash::Hash,
+    str::FromStr,
+};
+
+/// `#[derive(LineFormat)]` with `#[line("Card {id}: {win_nb} | {my_nb}")]`
+/// implements `FromPart` and `FromStr` from the shape of a line, see `aoc_format`.
+/// A malformed format string is a compile error, e.g. an unclosed placeholder:
+///
+/// ```compile_fail
+/// use advent_of_code::parse::LineFormat;
+///
+/// #[derive(LineFormat)]
+/// #[line("Card {id: {numbers}")]
+/// struct Card {
+///     id: u32,
+///     numbers: Vec<u32>,
+/// }
+/// ```
+pub use aoc_format::LineFormat;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} line {} column {}: expected {}, found {:?}",
+            self.day, self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A non blank line of a puzzle input, remembering where it comes from so
+/// that any piece of it can be reported in a `ParseError`.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u32,
+    /// 1-based line number in the input
+    pub number: usize,
+    /// The line without its indentation and trailing whitespace (including `\r`)
+    pub text: &'a str,
+    raw: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Build an error pointing at `found`. When `found` is a slice of this line
+    /// the column is computed from its position, otherwise the line start is used.
+    pub fn error(&self, found: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.raw.as_ptr() as usize;
+        let found_start = found.as_ptr() as usize;
+        let column = if found_start >= start && found_start <= start + self.raw.len() {
+            found_start - start + 1
+        } else {
+            1
+        };
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// `split_once` on a slice of this line
+    pub fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error(part, format!("a `{delimiter}` separator")))
+    }
+
+    /// Parse a slice of this line, ignoring surrounding whitespace
+    pub fn parse<T: FromStr>(&self, part: &'a str, expected: &str) -> Result<T, ParseError> {
+        let part = part.trim();
+        part.parse().map_err(|_| self.error(part, expected))
+    }
+
+    /// Parse every whitespace separated value of a slice of this line
+    pub fn parse_all<T: FromStr>(
+        &self,
+        part: &'a str,
+        expected: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        part.split_whitespace()
+            .map(|x| self.parse(x, expected))
+            .collect()
+    }
+
+    /// Parse every integer of a slice of this line whatever separates them,
+    /// e.g. `1,1,3` or `x=-2, y=15`. A `-` right before digits makes them negative,
+    /// which is an error for unsigned types.
+    pub fn integers<T: FromStr>(
+        &self,
+        part: &'a str,
+        expected: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        let bytes = part.as_bytes();
+        let mut res = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            res.push(self.parse(&part[start..i], expected)?);
+        }
+        Ok(res)
+    }
+
+    /// Split a slice of this line on `separator`, trimming each item
+    pub fn list(&self, part: &'a str, separator: &str) -> Vec<&'a str> {
+        part.split(separator).map(str::trim).collect()
+    }
+
+    /// `strip_prefix` on a slice of this line, ignoring surrounding whitespace
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        let part = part.trim();
+        part.strip_prefix(prefix)
+            .ok_or_else(|| self.error(part, format!("`{prefix}`")))
+    }
+
+    /// `strip_suffix` on a slice of this line, ignoring surrounding whitespace
+    pub fn strip_suffix(&self, part: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        let part = part.trim();
+        part.strip_suffix(suffix)
+            .ok_or_else(|| self.error(part, format!("`{suffix}`")))
+    }
+
+    /// The inside of `<open>...<close>`, e.g. `BBB, CCC` of `(BBB, CCC)`
+    pub fn delimited(&self, part: &'a str, open: &str, close: &str) -> Result<&'a str, ParseError> {
+        let inner = self.strip_prefix(part, open)?;
+        self.strip_suffix(inner, close)
+    }
+
+    /// Split a `key <separator> value` slice of this line, e.g. `AAA = (BBB, CCC)`,
+    /// trimming both sides
+    pub fn key_value(
+        &self,
+        part: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        let (key, value) = self.split_once(part, separator)?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// Split a `<label>: <value>` line, e.g. `Card 1: 41 48 | 83 86`
+    pub fn labelled(&self, part: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+        self.key_value(part, ":")
+    }
+
+    /// The value of a `<label>: <value>` line with a fixed label, e.g. `Time:`
+    pub fn label(&self, part: &'a str, label: &str) -> Result<&'a str, ParseError> {
+        let value = self.strip_prefix(part, label)?;
+        Ok(self.strip_prefix(value, ":")?.trim())
+    }
+
+    /// Split a slice of this line around the literals of a `LineFormat`, there
+    /// is one part less than literals. Whitespace around the literals is ignored
+    /// and a literal made of whitespace matches any run of whitespace.
+    pub fn split_format(
+        &self,
+        part: &'a str,
+        format: &str,
+        literals: &[&str],
+    ) -> Result<Vec<&'a str>, ParseError> {
+        let expected = |literal: &str| format!("`{}` of `{format}`", literal.trim());
+        let (prefix, literals) = literals.split_first().expect("at least one literal");
+        let (suffix, separators) = literals.split_last().unwrap_or((&"", &[]));
+        let mut rest = part.trim();
+        rest = rest
+            .strip_prefix(prefix.trim())
+            .ok_or_else(|| self.error(rest, expected(prefix)))?
+            .trim_start();
+        let mut res = Vec::with_capacity(literals.len());
+        for separator in separators {
+            let split = match separator.trim() {
+                "" => rest.split_once(char::is_whitespace),
+                separator => rest.split_once(separator),
+            };
+            let (value, next) = split.ok_or_else(|| self.error(rest, expected(separator)))?;
+            res.push(value.trim());
+            rest = next.trim_start();
+        }
+        if !literals.is_empty() {
+            let value = rest
+                .strip_suffix(suffix.trim())
+                .ok_or_else(|| self.error(rest, expected(suffix)))?;
+            res.push(value.trim());
+        }
+        Ok(res)
+    }
+
+    /// Convert each char of a slice of this line, e.g. a row of a grid
+    pub fn parse_chars<T>(
+        &self,
+        part: &'a str,
+        expected: &str,
+        mut to_value: impl FnMut(char) -> Option<T>,
+    ) -> Result<Vec<T>, ParseError> {
+        part.char_indices()
+            .map(|(i, c)| {
+                to_value(c).ok_or_else(|| self.error(&part[i..i + c.len_utf8()], expected))
+            })
+            .collect()
+    }
+}
+
+/// Iterate over the non blank lines of `input`, works with both LF and CRLF files
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, raw)| !raw.trim().is_empty())
+        .map(move |(i, raw)| Line {
+            day,
+            number: i + 1,
+            text: raw.trim(),
+            raw,
+        })
+}
+
+/// Group the lines of `input` into blocks separated by blank lines
+pub fn blocks(day: u32, input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut res: Vec<Vec<Line>> = vec![];
+    let mut previous_number = 0;
+    for line in lines(day, input) {
+        if res.is_empty() || line.number > previous_number + 1 {
+            res.push(vec![]);
+        }
+        previous_number = line.number;
+        res.last_mut().unwrap().push(line);
+    }
+    res
+}
+
+/// A value parsed from a slice of a `Line`, reporting errors at its position.
+/// Implemented by `#[derive(LineFormat)]`, by the primitive types and by
+/// collections of whitespace separated values.
+pub trait FromPart: Sized {
+    fn from_part<'a>(line: &Line<'a>, part: &'a str, expected: &str) -> Result<Self, ParseError>;
+}
+
+macro_rules! from_part_with_from_str {
+    ($($t:ty),*) => {
+        $(
+            impl FromPart for $t {
+                fn from_part<'a>(line: &Line<'a>, part: &'a str, expected: &str) -> Result<Self, ParseError> {
+                    line.parse(part, expected)
+                }
+            }
+        )*
+    };
+}
+
+from_part_with_from_str!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String
+);
+
+impl<T: FromPart> FromPart for Vec<T> {
+    fn from_part<'a>(line: &Line<'a>, part: &'a str, expected: &str) -> Result<Self, ParseError> {
+        part.split_whitespace()
+            .map(|x| T::from_part(line, x, expected))
+            .collect()
+    }
+}
+
+impl<T: FromPart + Eq + Hash> FromPart for HashSet<T> {
+    fn from_part<'a>(line: &Line<'a>, part: &'a str, expected: &str) -> Result<Self, ParseError> {
+        part.split_whitespace()
+            .map(|x| T::from_part(line, x, expected))
+            .collect()
+    }
+}
+
+impl<T: FromPart + Ord> FromPart for BTreeSet<T> {
+    fn from_part<'a>(line: &Line<'a>, part: &'a str, expected: &str) -> Result<Self, ParseError> {
+        part.split_whitespace()
+            .map(|x| T::from_part(line, x, expected))
+            .collect()
+    }
+}
+
+/// Parse every non blank line of `input` as a `T`
+pub fn parse_lines<T: FromPart>(
+    day: u32,
+    input: &str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    lines(day, input)
+        .map(|line| T::from_part(&line, line.text, expected))
+        .collect()
+}
+
+/// `FromStr` of the `LineFormat` types, `FromStr` does not know the day so errors
+/// are reported for day 0
+pub fn from_str<T: FromPart>(s: &str) -> Result<T, ParseError> {
+    let line = lines(0, s).next().ok_or_else(|| missing(0, s, "a line"))?;
+    T::from_part(&line, line.text, "a line")
+}
+
+/// A block of lines whose first line is a `<label>: <value>` header, e.g.
+/// `seeds: 79 14` followed by no line or `seed-to-soil map:` followed by ranges
+#[derive(Debug, Clone)]
+pub struct Section<'a> {
+    pub header: Line<'a>,
+    pub label: &'a str,
+    /// What follows the `:` of the header, empty for multi-line sections
+    pub value: &'a str,
+    pub lines: Vec<Line<'a>>,
+}
+
+impl<'a> Section<'a> {
+    /// The value of a section whose label must be `label`
+    pub fn value_of(&self, label: &str) -> Result<&'a str, ParseError> {
+        if self.label != label {
+            return Err(self.header.error(self.label, format!("`{label}`")));
+        }
+        Ok(self.value)
+    }
+}
+
+/// Split `input` into labelled sections separated by blank lines
+pub fn sections(day: u32, input: &str) -> Result<Vec<Section<'_>>, ParseError> {
+    blocks(day, input)
+        .into_iter()
+        .map(|block| {
+            let header = block[0];
+            let (label, value) = header.labelled(header.text)?;
+            Ok(Section {
+                header,
+                label,
+                value,
+                lines: block[1..].to_vec(),
+            })
+        })
+        .collect()
+}
+
+/// Error for an input missing some expected content
+pub fn missing(day: u32, input: &str, expected: impl Into<String>) -> ParseError {
+    ParseError {
+        day,
+        line: input.lines().count() + 1,
+        column: 1,
+        text: String::new(),
+        expected: expected.into(),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::HashSet;
+
+    use super::{blocks, lines, parse_lines, sections, LineFormat, ParseError};
+
+    #[test]
+    fn test_lines_skip_blank_and_crlf() {
+        let input = "a b\r\n\r\n  c d  \r\n";
+        let res: Vec<_> = lines(1, input).map(|l| (l.number, l.text)).collect();
+        assert_eq!(res, vec![(1, "a b"), (3, "c d")]);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\n\nc\n";
+        let res: Vec<Vec<_>> = blocks(1, input)
+            .into_iter()
+            .map(|b| b.into_iter().map(|l| l.text).collect())
+            .collect();
+        assert_eq!(res, vec![vec!["a", "b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn test_error_position() {
+        let line = lines(4, "ok\n  Card 1: 41 x8 | 83").nth(1).unwrap();
+        let (_, numbers) = line.split_once(line.text, ":").unwrap();
+        let err = line.parse_all::<u32>(numbers, "a number").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                day: 4,
+                line: 2,
+                column: 14,
+                text: "x8".to_string(),
+                expected: "a number".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "day 4 line 2 column 14: expected a number, found \"x8\""
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = lines(1, "x=-2, y=15 | 1,1,3").next().unwrap();
+        assert_eq!(
+            line.integers::<i32>(line.text, "a number").unwrap(),
+            vec![-2, 15, 1, 1, 3]
+        );
+        let err = line.integers::<u32>(line.text, "a size").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "-2"));
+        assert_eq!(line.integers::<u32>("a - b", "a size").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_key_value() {
+        let line = lines(8, "AAA = (BBB, CCC)").next().unwrap();
+        let (key, value) = line.key_value(line.text, "=").unwrap();
+        assert_eq!(key, "AAA");
+        let value = line.delimited(value, "(", ")").unwrap();
+        assert_eq!(line.list(value, ","), vec!["BBB", "CCC"]);
+        let err = line.delimited(key, "(", ")").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (1, "`(`"));
+    }
+
+    #[test]
+    fn test_label() {
+        let line = lines(6, "Time:      7  15   30").next().unwrap();
+        let times = line.label(line.text, "Time").unwrap();
+        assert_eq!(
+            line.parse_all::<u32>(times, "a time").unwrap(),
+            vec![7, 15, 30]
+        );
+        assert!(line.label(line.text, "Distance").is_err());
+        let line = lines(4, "Card 1: 41 48 | 83").next().unwrap();
+        assert_eq!(line.labelled(line.text).unwrap(), ("Card 1", "41 48 | 83"));
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let line = lines(8, "LRxL").next().unwrap();
+        let to_bool = |c| match c {
+            'L' => Some(true),
+            'R' => Some(false),
+            _ => None,
+        };
+        let err = line
+            .parse_chars(line.text, "`L` or `R`", to_bool)
+            .unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "x"));
+        assert_eq!(
+            line.parse_chars("RL", "`L` or `R`", to_bool).unwrap(),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+        let res = sections(5, input).unwrap();
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].value_of("seeds").unwrap(), "79 14");
+        assert!(res[0].lines.is_empty());
+        assert_eq!(res[1].label, "seed-to-soil map");
+        assert_eq!(res[1].value, "");
+        assert_eq!(res[1].lines.len(), 2);
+        assert!(res[1].value_of("seeds").is_err());
+        assert!(sections(5, "no label").is_err());
+    }
+
+    #[derive(Debug, PartialEq, LineFormat)]
+    #[line("Card {id}: {win_nb} | {my_nb}")]
+    struct Card {
+        id: u32,
+        win_nb: HashSet<u32>,
+        my_nb: Vec<u32>,
+    }
+
+    #[derive(Debug, PartialEq, LineFormat)]
+    #[line("{name} = ({left:,}) {{{size}}}")]
+    struct Node {
+        name: String,
+        left: Vec<i64>,
+        size: u8,
+    }
+
+    #[test]
+    fn test_line_format() {
+        let card: Card = "Card   1: 41 48 |  83 86 41".parse().unwrap();
+        assert_eq!(
+            card,
+            Card {
+                id: 1,
+                win_nb: HashSet::from([41, 48]),
+                my_nb: vec![83, 86, 41],
+            }
+        );
+        let node: Node = "AAA = (1, -2,3) {4}".parse().unwrap();
+        assert_eq!(
+            node,
+            Node {
+                name: "AAA".to_string(),
+                left: vec![1, -2, 3],
+                size: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_line_format_errors() {
+        let err = parse_lines::<Card>(4, "Card 1: 1 | 2\nCard 2: 1 2 3", "a card").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 4 line 2 column 9: expected `|` of `Card {id}: {win_nb} | {my_nb}`, found \"1 2 3\""
+        );
+        let err = parse_lines::<Card>(4, "Card 1: 1 x | 2", "a card").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 4 line 1 column 11: expected the `win_nb` field, found \"x\""
+        );
+        assert!("Game 1: 1 | 2".parse::<Card>().is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::geom::Point;
+use crate::math;
+
+/// A simple polygon on lattice points, the last vertex being linked back to the
+/// first one. Vertices may be collinear, like every cell of a path on a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: impl IntoIterator<Item = Point>) -> Self {
+        Polygon {
+            vertices: vertices.into_iter().collect(),
+        }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Every side as its two ends, the closing one included
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    /// Twice the signed area by the shoelace formula, which keeps it an integer.
+    /// It is positive when the vertices go clockwise on screen, `y` growing
+    /// downwards.
+    pub fn double_signed_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.double_signed_area().unsigned_abs() as f64 / 2.0
+    }
+
+    /// Euclidean length of the sides
+    pub fn perimeter(&self) -> f64 {
+        self.edges()
+            .map(|(a, b)| ((b.x - a.x) as f64).hypot((b.y - a.y) as f64))
+            .sum()
+    }
+
+    /// Number of lattice points on the sides, vertices included
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| math::gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside, from Pick's theorem
+    /// `area = interior + boundary / 2 - 1`. A polygon without area, like one
+    /// with less than 3 vertices or only collinear ones, has none.
+    pub fn interior_points(&self) -> u64 {
+        let double_area = self.double_signed_area().unsigned_abs();
+        if double_area == 0 {
+            return 0;
+        }
+        // only a polygon that is not simple can have more boundary points
+        (double_area + 2).saturating_sub(self.boundary_points()) / 2
+    }
+
+    pub fn on_boundary(&self, p: Point) -> bool {
+        self.edges().any(|(a, b)| {
+            cross(b - a, p - a) == 0
+                && a.x.min(b.x) <= p.x
+                && p.x <= a.x.max(b.x)
+                && a.y.min(b.y) <= p.y
+                && p.y <= a.y.max(b.y)
+        })
+    }
+
+    /// Whether `p` is strictly inside, by casting a ray towards growing `x` and
+    /// counting the sides it crosses
+    pub fn contains(&self, p: Point) -> bool {
+        if self.on_boundary(p) {
+            return false;
+        }
+        self.edges()
+            .filter(|&(a, b)| (a.y > p.y) != (b.y > p.y))
+            // the crossing is right of `p` when `p` is on the left of the side
+            // going down, or on its right going up
+            .filter(|&(a, b)| (cross(b - a, p - a) > 0) == (b.y > a.y))
+            .count()
+            % 2
+            == 1
+    }
+}
+
+fn cross(u: Point, v: Point) -> i64 {
+    u.x * v.y - u.y * v.x
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::Polygon;
+    use crate::geom::Point;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        Polygon::new(vertices.iter().map(|&p| Point::from(p)))
+    }
+
+    #[test]
+    fn test_area() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.double_signed_area(), 32);
+        assert_eq!(square.area(), 16.0);
+        assert_eq!(square.perimeter(), 16.0);
+        let reversed = polygon(&[(0, 4), (4, 4), (4, 0), (0, 0)]);
+        assert_eq!(reversed.double_signed_area(), -32);
+        let triangle = polygon(&[(0, 0), (3, 0), (0, 4)]);
+        assert_eq!(triangle.area(), 6.0);
+        assert_eq!(triangle.perimeter(), 12.0);
+    }
+
+    #[test]
+    fn test_pick() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        // the same square with every lattice point of its sides as a vertex
+        let mut cells: Vec<(i64, i64)> = (0..4).map(|x| (x, 0)).collect();
+        cells.extend((0..4).map(|y| (4, y)));
+        cells.extend((1..=4).rev().map(|x| (x, 4)));
+        cells.extend((1..=4).rev().map(|y| (0, y)));
+        let path = polygon(&cells);
+        assert_eq!(path.boundary_points(), 16);
+        assert_eq!(path.interior_points(), 9);
+        let triangle = polygon(&[(0, 0), (3, 0), (0, 4)]);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[test]
+    fn test_pick_without_area() {
+        assert_eq!(polygon(&[]).interior_points(), 0);
+        assert_eq!(polygon(&[(1, 1)]).interior_points(), 0);
+        assert_eq!(polygon(&[(0, 0), (3, 4)]).interior_points(), 0);
+        assert_eq!(polygon(&[(0, 0), (1, 0), (2, 0)]).interior_points(), 0);
+        // there and back along the same cells, like a loop path folded on itself
+        let folded = polygon(&[(0, 0), (0, 1), (0, 2), (0, 1)]);
+        assert_eq!(folded.interior_points(), 0);
+    }
+
+    #[test]
+    fn test_contains() {
+        // a U shape opening upwards
+        let u = polygon(&[
+            (0, 0),
+            (1, 0),
+            (1, 2),
+            (3, 2),
+            (3, 0),
+            (4, 0),
+            (4, 3),
+            (0, 3),
+        ]);
+        assert!(!u.contains(Point::new(2, 1)));
+        assert!(u.on_boundary(Point::new(2, 2)));
+        assert!(!u.contains(Point::new(2, 2)));
+        assert!(!u.contains(Point::new(5, 1)));
+        assert!(!u.contains(Point::new(-1, 1)));
+        // its only lattice points are on its sides
+        assert_eq!(u.interior_points(), 0);
+        assert!((0..5).all(|y| (0..5).all(|x| !u.contains(Point::new(x, y)))));
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        let inside = (-1..6)
+            .flat_map(|y| (-1..6).map(move |x| Point::new(x, y)))
+            .filter(|&p| square.contains(p))
+            .count();
+        assert_eq!(inside as u64, square.interior_points());
+        assert!(square.contains(Point::new(1, 3)));
+        assert!(square.on_boundary(Point::new(4, 2)));
+    }
+}
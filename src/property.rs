@@ -0,0 +1,261 @@
+use std::{
+    env,
+    fmt::{Debug, Display},
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// Environment variable replacing the seed of every property, to replay a
+/// failure or to explore other cases
+pub const SEED_VAR: &str = "AOC_PROP_SEED";
+
+/// Shrinking stops after this many smaller failing inputs, in case a shrink
+/// function does not converge
+const MAX_SHRINKS: usize = 10_000;
+
+/// Small seeded generator (SplitMix64), so that a failing case can be replayed
+/// from its seed without depending on a random crate
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `range`, up to a negligible bias
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+        let len = end.abs_diff(start).wrapping_add(1);
+        if len == 0 {
+            return self.next_u64() as i64;
+        }
+        start.wrapping_add((self.next_u64() % len) as i64)
+    }
+
+    pub fn range_u64(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// Between `len.start()` and `len.end()` values of `item`
+    pub fn vec<T>(
+        &mut self,
+        len: RangeInclusive<usize>,
+        mut item: impl FnMut(&mut Rng) -> T,
+    ) -> Vec<T> {
+        let (start, end) = len.into_inner();
+        let len = self.range_u64(start as u64..=end as u64) as usize;
+        (0..len).map(|_| item(self)).collect()
+    }
+}
+
+/// Smaller values to try for `value`, closest to 0 first
+pub fn shrink_u64(value: u64) -> Vec<u64> {
+    let mut res = vec![0, value / 2, value.saturating_sub(1)];
+    res.dedup();
+    res.retain(|&x| x < value);
+    res
+}
+
+/// Smaller values to try for `value`, moving toward 0 from either side
+pub fn shrink_i64(value: i64) -> Vec<i64> {
+    let mut res = vec![0, value / 2, value - value.signum()];
+    res.dedup();
+    res.retain(|&x| x.unsigned_abs() < value.unsigned_abs());
+    res
+}
+
+/// `values` with one item removed, then with one item shrunk
+pub fn shrink_vec<T: Clone>(values: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut res = vec![];
+    for i in 0..values.len() {
+        let mut smaller = values.to_vec();
+        smaller.remove(i);
+        res.push(smaller);
+    }
+    for (i, value) in values.iter().enumerate() {
+        for smaller in shrink(value) {
+            let mut values = values.to_vec();
+            values[i] = smaller;
+            res.push(values);
+        }
+    }
+    res
+}
+
+/// A case for which a property does not hold, shrunk as far as possible
+#[derive(Debug, Clone)]
+pub struct Failure<T> {
+    pub seed: u64,
+    pub case: usize,
+    /// Input generated for the case
+    pub original: T,
+    /// Smallest failing input found from `original`
+    pub shrunk: T,
+    pub shrinks: usize,
+    pub message: String,
+}
+
+impl<T: Debug> Display for Failure<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "property failed for case {} of seed {} (replay with {SEED_VAR}={})",
+            self.case, self.seed, self.seed
+        )?;
+        writeln!(
+            f,
+            "minimal input after {} shrinks: {:#?}",
+            self.shrinks, self.shrunk
+        )?;
+        writeln!(f, "error: {}", self.message)?;
+        write!(f, "original input: {:?}", self.original)
+    }
+}
+
+/// Outcome of `property`, a panic counting as a failure so that it is shrunk too
+fn holds<T>(property: &impl Fn(&T) -> Result<(), String>, input: &T) -> Result<(), String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| property(input))) {
+        Ok(res) => res,
+        Err(payload) => Err(match payload.downcast_ref::<&str>() {
+            Some(message) => format!("panicked: {message}"),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => format!("panicked: {message}"),
+                None => "panicked".to_string(),
+            },
+        }),
+    }
+}
+
+/// Check `property` on `cases` inputs made by `generate`. Each case gets its own
+/// generator seeded from `seed`, or from `$AOC_PROP_SEED` when set. The first
+/// failing input is shrunk by repeatedly taking the first candidate of `shrink`
+/// that still fails.
+pub fn check<T: Clone + Debug>(
+    seed: u64,
+    cases: usize,
+    mut generate: impl FnMut(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Result<(), Failure<T>> {
+    let seed = env::var(SEED_VAR)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(seed);
+    let mut rng = Rng::new(seed);
+    for case in 0..cases {
+        let input = generate(&mut Rng::new(rng.next_u64()));
+        let Err(message) = holds(&property, &input) else {
+            continue;
+        };
+        let mut failure = Failure {
+            seed,
+            case,
+            original: input.clone(),
+            shrunk: input,
+            shrinks: 0,
+            message,
+        };
+        'shrink: while failure.shrinks < MAX_SHRINKS {
+            for candidate in shrink(&failure.shrunk) {
+                if let Err(message) = holds(&property, &candidate) {
+                    failure.shrunk = candidate;
+                    failure.message = message;
+                    failure.shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        return Err(failure);
+    }
+    Ok(())
+}
+
+/// `check` for tests, panicking with the shrunk input of a failure
+pub fn assert<T: Clone + Debug>(
+    seed: u64,
+    cases: usize,
+    generate: impl FnMut(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    if let Err(failure) = check(seed, cases, generate, shrink, property) {
+        panic!("{failure}");
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{check, shrink_i64, shrink_u64, shrink_vec, Rng};
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_ne!(a.next_u64(), Rng::new(43).next_u64());
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&a.range(-3..=3)));
+            assert!((5..=6).contains(&a.range_u64(5..=6)));
+            assert!((1..=4).contains(&a.vec(1..=4, Rng::bool).len()));
+        }
+        assert_eq!(a.range(7..=7), 7);
+        a.range(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(shrink_u64(10), [0, 5, 9]);
+        assert_eq!(shrink_u64(1), [0]);
+        assert_eq!(shrink_u64(0), [] as [u64; 0]);
+        assert_eq!(shrink_i64(-4), [0, -2, -3]);
+        assert_eq!(
+            shrink_vec(&[1, 2], |&x| shrink_u64(x)),
+            [vec![2], vec![1], vec![0, 2], vec![1, 0], vec![1, 1]]
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let generate = |rng: &mut Rng| rng.vec(0..=20, |rng| rng.range_u64(0..=1000));
+        let shrink = |values: &Vec<u64>| shrink_vec(values, |&x| shrink_u64(x));
+        assert!(check(1, 200, generate, shrink, |_| Ok(())).is_ok());
+        // no item can be removed nor decremented without the sum going below 100
+        let failure = check(1, 200, generate, shrink, |values| {
+            let sum: u64 = values.iter().sum();
+            (sum < 100).then_some(()).ok_or(format!("sum {sum}"))
+        })
+        .unwrap_err();
+        assert!(failure.shrinks > 0);
+        assert_eq!(failure.message, "sum 100");
+        // panics are failures too
+        let failure = check(1, 200, generate, shrink, |values| {
+            assert!(values.len() < 3, "too long");
+            Ok(())
+        })
+        .unwrap_err();
+        assert_eq!(failure.shrunk, [0, 0, 0]);
+        assert_eq!(failure.message, "panicked: too long");
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt::Write;
+
+use crate::geom::Point;
+use crate::grid::Grid;
+
+/// Colours of the highlighted cells, the standard ANSI ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EColor {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl EColor {
+    /// Escape sequence printing the next chars bold in this colour
+    fn escape(self) -> &'static str {
+        match self {
+            EColor::Red => "\x1b[1;31m",
+            EColor::Green => "\x1b[1;32m",
+            EColor::Yellow => "\x1b[1;33m",
+            EColor::Blue => "\x1b[1;34m",
+            EColor::Magenta => "\x1b[1;35m",
+            EColor::Cyan => "\x1b[1;36m",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// `Plain` only writes the chars, for snapshot tests and files, `Ansi` colours
+/// the highlighted cells for a terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EStyle {
+    Plain,
+    Ansi,
+}
+
+/// A grid drawn as text with highlighted cells. Each highlight gets a line of
+/// the legend with its number of cells, the last highlight of a cell giving it
+/// its colour.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    chars: Grid<char>,
+    colors: Grid<Option<EColor>>,
+    legend: Vec<(String, EColor, usize)>,
+}
+
+impl Canvas {
+    pub fn new<T>(grid: &Grid<T>, to_char: impl FnMut(&T) -> char) -> Self {
+        Canvas {
+            chars: grid.map(to_char),
+            colors: Grid::filled(grid.width(), grid.height(), None),
+            legend: vec![],
+        }
+    }
+
+    /// Draw `c` at `pos`, ignored outside of the canvas
+    pub fn set(&mut self, pos: Point, c: char) -> &mut Self {
+        if let Some(cell) = self.chars.get_mut(pos) {
+            *cell = c;
+        }
+        self
+    }
+
+    /// Colour `positions`, those outside of the canvas being ignored
+    pub fn highlight(
+        &mut self,
+        label: &str,
+        color: EColor,
+        positions: impl IntoIterator<Item = Point>,
+    ) -> &mut Self {
+        let mut count = 0;
+        for pos in positions {
+            if let Some(cell) = self.colors.get_mut(pos) {
+                *cell = Some(color);
+                count += 1;
+            }
+        }
+        self.legend.push((label.to_string(), color, count));
+        self
+    }
+
+    pub fn highlight_rows(
+        &mut self,
+        label: &str,
+        color: EColor,
+        rows: impl IntoIterator<Item = usize>,
+    ) -> &mut Self {
+        let width = self.chars.width() as i64;
+        let positions = rows
+            .into_iter()
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y as i64)));
+        self.highlight(label, color, positions)
+    }
+
+    pub fn highlight_columns(
+        &mut self,
+        label: &str,
+        color: EColor,
+        columns: impl IntoIterator<Item = usize>,
+    ) -> &mut Self {
+        let height = self.chars.height() as i64;
+        let positions = columns
+            .into_iter()
+            .flat_map(|x| (0..height).map(move |y| Point::new(x as i64, y)));
+        self.highlight(label, color, positions)
+    }
+
+    /// The rows of the grid, then the legend after an empty line
+    pub fn render(&self, style: EStyle) -> String {
+        let mut res = String::new();
+        for (chars, colors) in self.chars.rows().zip(self.colors.rows()) {
+            let mut current = None;
+            for (&c, &color) in chars.iter().zip(colors) {
+                if style == EStyle::Ansi && color != current {
+                    res.push_str(color.map_or(RESET, EColor::escape));
+                    current = color;
+                }
+                res.push(c);
+            }
+            if current.is_some() {
+                res.push_str(RESET);
+            }
+            res.push('\n');
+        }
+        if !self.legend.is_empty() {
+            res.push('\n');
+        }
+        for (label, color, count) in &self.legend {
+            if style == EStyle::Ansi {
+                res.push_str(color.escape());
+                res.push('■');
+                res.push_str(RESET);
+                res.push(' ');
+            }
+            let plural = if *count == 1 { "" } else { "s" };
+            writeln!(res, "{label}: {count} cell{plural}").unwrap();
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{Canvas, EColor, EStyle};
+    use crate::geom::Point;
+    use crate::grid::Grid;
+
+    fn canvas() -> Canvas {
+        let grid: Grid<char> = Grid::parse_with(0, "#..\n.#.\n..#", "", Some).unwrap();
+        Canvas::new(&grid, |&c| c)
+    }
+
+    #[test]
+    fn test_plain() {
+        assert_eq!(canvas().render(EStyle::Plain), "#..\n.#.\n..#\n");
+        let mut canvas = canvas();
+        canvas
+            .highlight(
+                "diagonal",
+                EColor::Green,
+                [Point::new(0, 0), Point::new(1, 1)],
+            )
+            .highlight_rows("middle row", EColor::Blue, [1])
+            .highlight("outside", EColor::Red, [Point::new(5, 5)])
+            .set(Point::new(2, 0), 'x');
+        assert_eq!(
+            canvas.render(EStyle::Plain),
+            "#.x\n.#.\n..#\n\ndiagonal: 2 cells\nmiddle row: 3 cells\noutside: 0 cells\n"
+        );
+    }
+
+    #[test]
+    fn test_ansi() {
+        let mut canvas = canvas();
+        canvas
+            .highlight_columns("first column", EColor::Yellow, [0])
+            .highlight("corner", EColor::Red, [Point::new(0, 2)]);
+        let rendered = canvas.render(EStyle::Ansi);
+        let mut lines = rendered.lines();
+        assert_eq!(lines.next(), Some("\x1b[1;33m#\x1b[0m.."));
+        assert_eq!(lines.next(), Some("\x1b[1;33m.\x1b[0m#."));
+        assert_eq!(lines.next(), Some("\x1b[1;31m.\x1b[0m.#"));
+        assert_eq!(
+            lines.nth(1),
+            Some("\x1b[1;33m■\x1b[0m first column: 3 cells")
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use aoc_runner::{ArcStr, Runner};
+
+use crate::explain::Breakdown;
+use crate::image::Image;
+use crate::parse::ParseError;
+use crate::render::EStyle;
+
+/// Generate the input of a day part, the returned `Runner` then solves it
+pub type Factory = fn(ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
+
+/// A solution registered in the `SOLUTIONS` of a year module
+pub struct Solution {
+    pub day: u32,
+    pub part: u32,
+    pub factory: Factory,
+}
+
+impl Solution {
+    pub const fn new(day: u32, part: u32, factory: Factory) -> Self {
+        Solution { day, part, factory }
+    }
+}
+
+/// Draw the input of a grid day with what its solution found, for debugging
+pub type Render = fn(&str, EStyle) -> Result<String, ParseError>;
+
+/// Draw the input of a grid day as an image, for inputs too large for `Render`
+pub type Draw = fn(&str) -> Result<Image, ParseError>;
+
+/// Explain how the answer of a day part comes from the input
+pub type Explain = fn(&str) -> Result<Breakdown, ParseError>;
+
+/// Every solution of one event, its inputs live in `input/<year>/`
+pub struct Year {
+    pub year: u32,
+    pub solutions: &'static [Solution],
+    /// Days that can be drawn by the `render` command
+    pub renders: &'static [(u32, Render)],
+    /// Days that can be drawn by `render --image`
+    pub images: &'static [(u32, Draw)],
+    /// Day parts whose answer can be broken down by `run --explain`
+    pub explains: &'static [(u32, u32, Explain)],
+}
+
+impl Year {
+    pub fn solution(&self, day: u32, part: u32) -> Option<&Solution> {
+        self.solutions
+            .iter()
+            .find(|s| s.day == day && s.part == part)
+    }
+
+    pub fn render(&self, day: u32) -> Option<Render> {
+        self.renders
+            .iter()
+            .find(|(d, _)| *d == day)
+            .map(|&(_, render)| render)
+    }
+
+    pub fn explain(&self, day: u32, part: u32) -> Option<Explain> {
+        self.explains
+            .iter()
+            .find(|(d, p, _)| *d == day && *p == part)
+            .map(|&(_, _, explain)| explain)
+    }
+
+    pub fn image(&self, day: u32) -> Option<Draw> {
+        self.images
+            .iter()
+            .find(|(d, _)| *d == day)
+            .map(|&(_, draw)| draw)
+    }
+}
+
+/// `Runner` of the solutions registered with `solution!`
+pub struct GeneratedRunner<T> {
+    input: T,
+    solver: fn(&T) -> String,
+}
+
+impl<T> GeneratedRunner<T> {
+    pub fn new(input: T, solver: fn(&T) -> String) -> Self {
+        GeneratedRunner { input, solver }
+    }
+}
+
+impl<T> Runner for GeneratedRunner<T> {
+    fn gen(_input: ArcStr) -> Self {
+        unreachable!("built by the factory of `solution!`")
+    }
+
+    fn run(&self) -> Box<dyn Display> {
+        Box::new((self.solver)(&self.input))
+    }
+
+    fn bench(&self, black_box: fn(&dyn Display)) {
+        black_box(&(self.solver)(&self.input))
+    }
+}
+
+/// Register a solution without `#[aoc]`, which only supports the year of `aoc_lib!`.
+/// The generator returns a `Result` like every generator of the crate:
+///
+/// ```ignore
+/// pub const SOLUTIONS: &[Solution] = &[
+///     solution!(1, 1, day1::input_generator => day1::part1),
+///     solution!(1, 2, day1::input_generator => day1::part2),
+/// ];
+/// ```
+#[macro_export]
+macro_rules! solution {
+    ($day:literal, $part:literal, $generator:path => $solver:path) => {
+        $crate::runner::Solution::new($day, $part, |input| {
+            let input = $generator(::std::borrow::Borrow::<str>::borrow(&input))?;
+            Ok(Box::new($crate::runner::GeneratedRunner::new(
+                input,
+                |input| $solver(input).to_string(),
+            )))
+        })
+    };
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub generator: Duration,
+    pub runner: Duration,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} - Part {}: {}\n\tgenerator: {:?},\n\trunner: {:?}",
+            self.day, self.part, self.answer, self.generator, self.runner
+        )
+    }
+}
+
+/// Which part failed while solving a day
+#[derive(Debug)]
+pub enum ERunError {
+    Generating(Box<dyn Error>),
+    Running(Box<dyn Error>),
+}
+
+impl Display for ERunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ERunError::Generating(e) => write!(f, "FAILED while generating: {e}"),
+            ERunError::Running(e) => write!(f, "FAILED while running: {e}"),
+        }
+    }
+}
+
+impl Error for ERunError {}
+
+/// Run the generator then the solver of `solution`, timing both separately
+pub fn run(solution: &Solution, input: &str) -> Result<Answer, ERunError> {
+    let input = ArcStr::from(input);
+    let start_time = Instant::now();
+    let runner = (solution.factory)(input).map_err(ERunError::Generating)?;
+    let inter_time = Instant::now();
+    let answer = runner.try_run().map_err(ERunError::Running)?;
+    let final_time = Instant::now();
+    Ok(Answer {
+        day: solution.day,
+        part: solution.part,
+        answer: answer.to_string(),
+        generator: inter_time - start_time,
+        runner: final_time - inter_time,
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{run, ERunError, Solution};
+    use crate::{parse::ParseError, y2023, YEARS};
+
+    fn solution(day: u32, part: u32) -> &'static Solution {
+        y2023::YEAR.solution(day, part).unwrap()
+    }
+
+    #[test]
+    fn test_solutions_are_sorted() {
+        for year in YEARS {
+            let keys: Vec<_> = year.solutions.iter().map(|s| (s.day, s.part)).collect();
+            let mut sorted = keys.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(keys, sorted, "solutions of {} are not sorted", year.year);
+        }
+        let years: Vec<_> = YEARS.iter().map(|y| y.year).collect();
+        assert!(years.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    fn count_lines(input: &str) -> Result<usize, ParseError> {
+        Ok(input.lines().count())
+    }
+
+    fn double(lines: &usize) -> usize {
+        lines * 2
+    }
+
+    #[test]
+    fn test_solution_macro() {
+        let solution: Solution = crate::solution!(1, 2, count_lines => double);
+        let answer = run(&solution, "a\nb\nc").unwrap();
+        assert_eq!((answer.day, answer.part), (1, 2));
+        assert_eq!(answer.answer, "6");
+    }
+
+    #[test]
+    fn test_run() {
+        let answer = run(solution(9, 1), "0 3 6 9 12 15\n1 3 6 10 15 21\n").unwrap();
+        assert_eq!((answer.day, answer.part), (9, 1));
+        assert_eq!(answer.answer, "46");
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        let err = run(solution(9, 2), "0 3 x").unwrap_err();
+        assert!(matches!(err, ERunError::Generating(_)));
+        assert_eq!(
+            err.to_string(),
+            "FAILED while generating: day 9 line 1 column 5: expected a history value, found \"x\""
+        );
+    }
+}
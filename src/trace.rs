@@ -0,0 +1,255 @@
+use std::{cell::RefCell, fmt::Display, fmt::Write, path::Path};
+
+/// Whether this build records the `trace!` events, see the `trace` feature
+pub const ENABLED: bool = cfg!(feature = "trace");
+
+/// Value of a field of an event, numbers staying numbers in JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i128),
+    Bool(bool),
+    Str(String),
+}
+
+macro_rules! value_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+value_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Value::Str(value.to_string())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Str(value.to_string())
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Value::Str(value.clone())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Str(value)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{value}"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Str(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+/// One step of a solver, emitted with `trace!`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+/// Record an event of a solver, e.g. `trace!("step", step = step, node = node)`.
+/// Without the `trace` feature it compiles to nothing and its values are never
+/// evaluated, so that benchmarks are unaffected.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! trace {
+    ($name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::is_capturing() {
+            $crate::trace::emit($crate::trace::Event {
+                name: $name,
+                fields: vec![$((stringify!($key), $crate::trace::Value::from($value))),*],
+            });
+        }
+    };
+}
+
+/// Record an event of a solver, e.g. `trace!("step", step = step, node = node)`.
+/// Without the `trace` feature it compiles to nothing and its values are never
+/// evaluated, so that benchmarks are unaffected.
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! trace {
+    ($name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if false {
+            $(let _ = &$value;)*
+        }
+    };
+}
+
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+pub fn is_capturing() -> bool {
+    EVENTS.with(|events| events.borrow().is_some())
+}
+
+/// Add `event` to the current capture, if any
+pub fn emit(event: Event) {
+    EVENTS.with(|events| {
+        if let Some(events) = events.borrow_mut().as_mut() {
+            events.push(event);
+        }
+    });
+}
+
+/// Run `f` and return the events it emitted
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<Event>) {
+    let previous = EVENTS.with(|events| events.replace(Some(vec![])));
+    let res = f();
+    let captured = EVENTS.with(|events| events.replace(previous));
+    (res, captured.unwrap_or_default())
+}
+
+/// `Text` writes a line `name key=value ...` per event under a header per part,
+/// `JsonLines` an object per line with the year, day and part of the event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EFormat {
+    Text,
+    JsonLines,
+}
+
+impl EFormat {
+    /// JSON Lines for `.jsonl` files, text otherwise
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("jsonl") => EFormat::JsonLines,
+            _ => EFormat::Text,
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            c if c.is_control() => write!(res, "\\u{:04x}", c as u32).unwrap(),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// The events of one part of a day
+pub fn format_events(year: u32, day: u32, part: u32, events: &[Event], format: EFormat) -> String {
+    let mut res = String::new();
+    if format == EFormat::Text {
+        writeln!(res, "Day {day} - Part {part}: {} events", events.len()).unwrap();
+    }
+    for event in events {
+        match format {
+            EFormat::Text => {
+                res.push_str(event.name);
+                for (key, value) in &event.fields {
+                    write!(res, " {key}={value}").unwrap();
+                }
+            }
+            EFormat::JsonLines => {
+                write!(
+                    res,
+                    "{{\"year\":{year},\"day\":{day},\"part\":{part},\"event\":{}",
+                    json_string(event.name)
+                )
+                .unwrap();
+                for (key, value) in &event.fields {
+                    let value = match value {
+                        Value::Str(s) => json_string(s),
+                        value => value.to_string(),
+                    };
+                    write!(res, ",{}:{value}", json_string(key)).unwrap();
+                }
+                res.push('}');
+            }
+        }
+        res.push('\n');
+    }
+    res
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{capture, emit, format_events, EFormat, Event, Value};
+
+    fn event() -> Event {
+        Event {
+            name: "step",
+            fields: vec![
+                ("step", Value::from(3usize)),
+                ("node", Value::from("A\"A")),
+                ("last", Value::from(false)),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_capture() {
+        emit(event());
+        let (res, events) = capture(|| {
+            emit(event());
+            let (_, inner) = capture(|| emit(event()));
+            assert_eq!(inner.len(), 1);
+            42
+        });
+        assert_eq!((res, events), (42, vec![event()]));
+        assert_eq!(capture(|| ()).1, vec![]);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format_events(2023, 8, 1, &[event()], EFormat::Text),
+            "Day 8 - Part 1: 1 events\nstep step=3 node=\"A\\\"A\" last=false\n"
+        );
+        assert_eq!(
+            format_events(2023, 8, 1, &[event()], EFormat::JsonLines),
+            "{\"year\":2023,\"day\":8,\"part\":1,\"event\":\"step\",\
+             \"step\":3,\"node\":\"A\\\"A\",\"last\":false}\n"
+        );
+    }
+
+    #[test]
+    fn test_macro() {
+        let mut evaluated = false;
+        let (_, events) = capture(|| {
+            crate::trace!(
+                "test",
+                value = {
+                    evaluated = true;
+                    1
+                }
+            );
+        });
+        // nothing is recorded nor even evaluated without the feature
+        assert_eq!(events.len(), super::ENABLED as usize);
+        assert_eq!(evaluated, super::ENABLED);
+    }
+}
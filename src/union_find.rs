@@ -0,0 +1,138 @@
+/// Disjoint sets of the elements `0..len`, merged with union by rank and found
+/// with path compression, so that both are close to constant time
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// `len` elements, each in its own set
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Representative of the set of `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut x = x;
+        while self.parents[x] != root {
+            (x, self.parents[x]) = (self.parents[x], root);
+        }
+        root
+    }
+
+    /// Merge the sets of `a` and `b`, `false` when they already were the same
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.ranks[a] < self.ranks[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        if self.ranks[a] == self.ranks[b] {
+            self.ranks[a] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set of `x`
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// Every set, in the order of their smallest element, each in increasing order
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let labels = self.labels();
+        let mut res = vec![vec![]; self.count];
+        for (x, label) in labels.into_iter().enumerate() {
+            res[label].push(x);
+        }
+        res
+    }
+
+    /// The set of every element numbered from 0 in the order of their smallest
+    /// element
+    pub fn labels(&mut self) -> Vec<usize> {
+        let mut label_of_root = vec![usize::MAX; self.len()];
+        let mut next = 0;
+        (0..self.len())
+            .map(|x| {
+                let root = self.find(x);
+                if label_of_root[root] == usize::MAX {
+                    label_of_root[root] = next;
+                    next += 1;
+                }
+                label_of_root[root]
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::UnionFind;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(8);
+        assert_eq!(sets.count(), 8);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.union(5, 7));
+        assert!(sets.same(0, 3) && !sets.same(0, 4));
+        assert_eq!((sets.size(2), sets.size(4), sets.size(7)), (4, 1, 2));
+        assert_eq!(sets.count(), 4);
+        assert_eq!(sets.labels(), vec![0, 0, 0, 0, 1, 2, 3, 2]);
+        assert_eq!(
+            sets.groups(),
+            vec![vec![0, 1, 2, 3], vec![4], vec![5, 7], vec![6]]
+        );
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let len = 100_000;
+        let mut sets = UnionFind::new(len);
+        for x in 1..len {
+            sets.union(x - 1, x);
+        }
+        assert_eq!(sets.count(), 1);
+        assert_eq!(sets.size(0), len);
+        assert!(sets.same(0, len - 1));
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use fancy_regex::Regex;
+
+use crate::explain::Breakdown;
+use crate::parse::{self, Line, ParseError};
+
+/// First and last digit of a line, as a two digit number
+fn calibration_part1(line: &Line) -> Result<u32, ParseError> {
+    let mut digits = line.text.chars().filter(|x| x.is_ascii_digit());
+    let first = digits
+        .next()
+        .ok_or_else(|| line.error(line.text, "at least one digit"))?;
+    let last = digits.next_back().unwrap_or(first);
+    Ok(format!("{first}{last}").parse::<u32>().unwrap())
+}
+
+#[aoc_generator(day1, part1)]
+fn input_generator_part1(input: &str) -> Result<Vec<u32>, ParseError> {
+    parse::lines(1, input)
+        .map(|line| calibration_part1(&line))
+        .collect()
+}
+
+fn parse_number(value: &str) -> u32 {
+    match value {
+        "one" | "1" => 1,
+        "two" | "2" => 2,
+        "three" | "3" => 3,
+        "four" | "4" => 4,
+        "five" | "5" => 5,
+        "six" | "6" => 6,
+        "seven" | "7" => 7,
+        "eight" | "8" => 8,
+        "nine" | "9" => 9,
+        _ => 0,
+    }
+}
+
+/// Digits and spelled out digits, the lookahead letting them overlap like `twone`
+fn digits_regex() -> Regex {
+    Regex::new(r"(?=(\d|one|two|three|four|five|six|seven|eight|nine))").unwrap()
+}
+
+/// First and last digit of a line, spelled out or not
+fn calibration_part2(re: &Regex, line: &Line) -> Result<u32, ParseError> {
+    let mut iter_match = re
+        .captures_iter(line.text)
+        .filter_map(|x| x.ok().and_then(|x| x.get(1)))
+        .map(|x| parse_number(x.as_str()));
+    let first_match = iter_match
+        .next()
+        .ok_or_else(|| line.error(line.text, "at least one digit or spelled out digit"))?;
+    let last_match = iter_match.last().unwrap_or(first_match);
+    Ok(first_match * 10 + last_match)
+}
+
+#[aoc_generator(day1, part2)]
+fn input_generator_part2(input: &str) -> Result<Vec<u32>, ParseError> {
+    let re = digits_regex();
+    parse::lines(1, input)
+        .map(|line| calibration_part2(&re, &line))
+        .collect()
+}
+
+#[aoc(day1, part1)]
+pub fn part1(input: &[u32]) -> u32 {
+    input.iter().sum()
+}
+
+#[aoc(day1, part2)]
+pub fn part2(input: &[u32]) -> u32 {
+    input.iter().sum()
+}
+
+fn explain(
+    input: &str,
+    mut calibration: impl FnMut(&Line) -> Result<u32, ParseError>,
+) -> Result<Breakdown, ParseError> {
+    let mut res = Breakdown::new("sum of the calibration values", &["line", "text", "value"]);
+    for line in parse::lines(1, input) {
+        res.push(&[&line.number, &line.text, &calibration(&line)?]);
+    }
+    Ok(res)
+}
+
+pub fn explain_part1(input: &str) -> Result<Breakdown, ParseError> {
+    explain(input, calibration_part1)
+}
+
+pub fn explain_part2(input: &str) -> Result<Breakdown, ParseError> {
+    let re = digits_regex();
+    explain(input, |line| calibration_part2(&re, line))
+}
+
+#[cfg(test)]
+pub mod tests {
+    crate::example_tests!(2023, 1);
+    crate::answer_tests!(2023, 1);
+}
@@ -0,0 +1,323 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use crate::geom::{Dir4, Point};
+use crate::graph;
+use crate::grid::Grid;
+use crate::image::{Image, Rgb};
+use crate::parse::{self, ParseError};
+use crate::render::{Canvas, EColor, EStyle};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ECell {
+    PVertical,
+    PHorizontal,
+    PUpLeft,
+    PUpRight,
+    PDownLeft,
+    PDownRight,
+    Ground,
+    Start,
+}
+
+impl ECell {
+    pub fn to_direction(cell: &ECell) -> &'static [Dir4] {
+        match cell {
+            ECell::PVertical => &[Dir4::Up, Dir4::Down],
+            ECell::PHorizontal => &[Dir4::Left, Dir4::Right],
+            ECell::PUpRight => &[Dir4::Up, Dir4::Right],
+            ECell::PUpLeft => &[Dir4::Up, Dir4::Left],
+            ECell::PDownLeft => &[Dir4::Down, Dir4::Left],
+            ECell::PDownRight => &[Dir4::Down, Dir4::Right],
+            ECell::Start => &Dir4::ALL,
+            _ => &[],
+        }
+    }
+}
+
+impl FromStr for ECell {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "|" => Ok(ECell::PVertical),
+            "-" => Ok(ECell::PHorizontal),
+            "L" => Ok(ECell::PUpRight),
+            "J" => Ok(ECell::PUpLeft),
+            "7" => Ok(ECell::PDownLeft),
+            "F" => Ok(ECell::PDownRight),
+            "." => Ok(ECell::Ground),
+            "S" => Ok(ECell::Start),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The sketch of the pipes, with the two directions of the main loop from the
+/// start since `S` hides its pipe
+pub struct Pipes {
+    start_pos: Point,
+    exits: [Dir4; 2],
+    grid: Grid<ECell>,
+}
+
+#[aoc_generator(day10)]
+fn input_generator(input: &str) -> Result<Pipes, ParseError> {
+    let grid = Grid::parse(10, input, "a pipe, `.` or `S`")?;
+    let start_pos = grid
+        .find(|&c| c == ECell::Start)
+        .ok_or_else(|| parse::missing(10, input, "a `S` start cell"))?;
+    // a second `S` would be a crossing of every direction for the walks
+    let second_start = grid.iter().filter(|(_, &c)| c == ECell::Start).nth(1);
+    if let Some((pos, _)) = second_start {
+        let line = parse::lines(10, input)
+            .nth(pos.y as usize)
+            .expect("a line per row of the grid");
+        let x = pos.x as usize;
+        return Err(line.error(&line.text[x..x + 1], "a single `S` start cell"));
+    }
+    let exits = start_exits(&grid, start_pos)
+        .ok_or_else(|| parse::missing(10, input, "a loop of pipes through `S`"))?;
+    Ok(Pipes {
+        start_pos,
+        exits,
+        grid,
+    })
+}
+
+fn match_direction(
+    grid: &Grid<ECell>,
+    current_pos: Point,
+    direction: Dir4,
+) -> Option<(Point, Dir4)> {
+    let cell_pos = current_pos + direction;
+    let contain_dir = direction.opposite();
+    let cell = grid.get(cell_pos)?;
+    if ECell::to_direction(cell).contains(&contain_dir) {
+        return Some((cell_pos, contain_dir));
+    }
+    None
+}
+
+/// The cells walked from the start towards `dir` when the pipes lead back to it,
+/// `None` for a dead end
+fn walk(grid: &Grid<ECell>, start_pos: Point, dir: Dir4) -> Option<Vec<Point>> {
+    let mut path = vec![start_pos];
+    let (mut pos, mut came_from) = match_direction(grid, start_pos, dir)?;
+    while pos != start_pos {
+        path.push(pos);
+        (pos, came_from) = ECell::to_direction(&grid[pos])
+            .iter()
+            .filter(|&&dir| dir != came_from)
+            .find_map(|&dir| match_direction(grid, pos, dir))?;
+    }
+    Some(path)
+}
+
+/// The cells of the main loop in the order they are walked from the start, the
+/// pipes next to the start that do not lead back to it being ignored
+fn loop_path(grid: &Grid<ECell>, start_pos: Point) -> Option<Vec<Point>> {
+    Dir4::ALL
+        .into_iter()
+        .find_map(|dir| walk(grid, start_pos, dir))
+}
+
+/// The two directions of the main loop from the start, `None` without a loop
+fn start_exits(grid: &Grid<ECell>, start_pos: Point) -> Option<[Dir4; 2]> {
+    let path = loop_path(grid, start_pos)?;
+    let exit = |pos: Point| Dir4::ALL.into_iter().find(|&dir| start_pos + dir == pos);
+    Some([exit(path[1])?, exit(*path.last()?)?])
+}
+
+/// The main loop as the distance from the start along the pipes of every cell
+/// in it: pipes only link to neighbours connecting back to them, and the start
+/// only to its two exits.
+fn main_loop(pipes: &Pipes) -> HashMap<Point, u64> {
+    let Pipes {
+        start_pos,
+        exits,
+        grid,
+    } = pipes;
+    let search = graph::bfs([*start_pos], |&pos| {
+        let directions = match grid[pos] {
+            ECell::Start => exits,
+            cell => ECell::to_direction(&cell),
+        };
+        directions
+            .iter()
+            .filter_map(move |&dir| match_direction(grid, pos, dir))
+            .map(|(next, _)| next)
+    });
+    search.distances
+}
+
+#[aoc(day10, part1)]
+pub fn part1(input: &Pipes) -> u64 {
+    main_loop(input).into_values().max().unwrap()
+}
+
+/// Tiles enclosed by the main loop, row by row
+fn enclosed_tiles(pipes: &Pipes, pipe_pos: &HashMap<Point, u64>) -> Vec<Point> {
+    let grid = &pipes.grid;
+    // The start only crosses the scanline when its pipe goes up, like `|`, `J` and `L`
+    let start_goes_up = pipes.exits.contains(&Dir4::Up);
+    let mut res = vec![];
+    for y in 0..grid.height() as i64 {
+        let mut inside = false;
+        for x in 0..grid.width() as i64 {
+            let pos = Point::new(x, y);
+            if pipe_pos.contains_key(&pos) {
+                let cell = grid[pos];
+                if [ECell::PVertical, ECell::PUpLeft, ECell::PUpRight].contains(&cell)
+                    || (cell == ECell::Start && start_goes_up)
+                {
+                    inside = !inside;
+                }
+            } else if inside {
+                res.push(pos);
+            }
+        }
+    }
+    res
+}
+
+#[aoc(day10, part2)]
+pub fn part2(input: &Pipes) -> u32 {
+    enclosed_tiles(input, &main_loop(input)).len() as u32
+}
+
+/// Draw the pipes as box lines, with the main loop and the tiles it encloses
+/// marked `I`
+pub fn render(input: &str, style: EStyle) -> Result<String, ParseError> {
+    let pipes = input_generator(input)?;
+    let pipe_pos = main_loop(&pipes);
+    let enclosed = enclosed_tiles(&pipes, &pipe_pos);
+    let mut canvas = Canvas::new(&pipes.grid, |cell| match cell {
+        ECell::PVertical => '│',
+        ECell::PHorizontal => '─',
+        ECell::PUpRight => '└',
+        ECell::PUpLeft => '┘',
+        ECell::PDownLeft => '┐',
+        ECell::PDownRight => '┌',
+        ECell::Ground => '.',
+        ECell::Start => 'S',
+    });
+    for &pos in &enclosed {
+        canvas.set(pos, 'I');
+    }
+    canvas
+        .highlight("main loop", EColor::Green, pipe_pos.into_keys())
+        .highlight("enclosed tiles", EColor::Yellow, enclosed);
+    Ok(canvas.render(style))
+}
+
+/// Draw every cell as 3x3 pixels: the pipes of the main loop in green, the other
+/// pipes in grey and the start in red, over enclosed tiles in yellow and outside
+/// ones in navy
+pub fn draw(input: &str) -> Result<Image, ParseError> {
+    let pipes = input_generator(input)?;
+    let pipe_pos = main_loop(&pipes);
+    let enclosed: HashSet<Point> = enclosed_tiles(&pipes, &pipe_pos).into_iter().collect();
+    Ok(Image::from_grid_scaled(
+        &pipes.grid,
+        3,
+        |pos, cell, inner| {
+            let center = Point::new(1, 1);
+            let directions = ECell::to_direction(cell);
+            let is_pipe = !directions.is_empty()
+                && (inner == center || directions.iter().any(|&dir| inner == center + dir));
+            match (cell, is_pipe) {
+                (ECell::Start, _) => Rgb::RED,
+                (_, true) if pipe_pos.contains_key(&pos) => Rgb::GREEN,
+                (_, true) => Rgb::GREY,
+                _ if enclosed.contains(&pos) => Rgb::YELLOW,
+                _ => Rgb::NAVY,
+            }
+        },
+    ))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{draw, input_generator, loop_path, part2, render, Pipes};
+    use crate::example;
+    use crate::geom::{Dir4, Point};
+    use crate::image::Rgb;
+    use crate::polygon::Polygon;
+    use crate::render::EStyle;
+
+    crate::example_tests!(2023, 10);
+    crate::answer_tests!(2023, 10);
+
+    /// Part 2 from the geometry of the loop rather than from its pipes: it is a
+    /// lattice polygon whose boundary points are its cells, so Pick's theorem
+    /// gives the enclosed tiles
+    fn part2_pick(input: &Pipes) -> u64 {
+        Polygon::new(loop_path(&input.grid, input.start_pos).unwrap()).interior_points()
+    }
+
+    #[test]
+    fn test_part2_methods_agree() {
+        for example in example::load(2023, 10).unwrap() {
+            let input = input_generator(&example.input).unwrap();
+            let polygon = Polygon::new(loop_path(&input.grid, input.start_pos).unwrap());
+            let contained = input
+                .grid
+                .positions()
+                .filter(|&p| polygon.contains(p))
+                .count();
+            assert_eq!(
+                part2(&input) as u64,
+                part2_pick(&input),
+                "{:?}",
+                example.path
+            );
+            assert_eq!(contained as u64, part2_pick(&input), "{:?}", example.path);
+        }
+    }
+
+    #[test]
+    fn test_loop_path() {
+        // the pipe above the start is a dead end, not an exit of the loop
+        let pipes = input_generator(".|...\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap();
+        assert_eq!(pipes.exits, [Dir4::Right, Dir4::Down]);
+        let path = loop_path(&pipes.grid, pipes.start_pos).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[1]), (pipes.start_pos, Point::new(2, 1)));
+        let error = input_generator(".|.\n.S-\n...").map(|_| ()).unwrap_err();
+        assert_eq!(error.expected, "a loop of pipes through `S`");
+        // from the second start the walk would go round the pipes above it forever
+        let error = input_generator("..F7\nS-SJ\n....").map(|_| ()).unwrap_err();
+        assert_eq!(error.expected, "a single `S` start cell");
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF";
+        assert_eq!(
+            render(input, EStyle::Plain).unwrap(),
+            "─└│┌┐\n┐S─┐│\n└│I││\n─└─┘│\n└│─┘┌\n\nmain loop: 8 cells\nenclosed tiles: 1 cell\n"
+        );
+        // the pipe above the start is not part of the loop
+        let stray = render(".|...\n.S-7.\n.|.|.\n.L-J.\n.....", EStyle::Plain).unwrap();
+        assert!(stray.ends_with("main loop: 8 cells\nenclosed tiles: 1 cell\n"));
+    }
+
+    #[test]
+    fn test_draw() {
+        let image = draw(".....\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap();
+        assert_eq!((image.pixels.width(), image.pixels.height()), (15, 15));
+        let pixel = |x, y| image.pixels[Point::new(x, y)];
+        // the start, the loop and its arms, the enclosed tile and the outside
+        assert_eq!(pixel(3, 3), Rgb::RED);
+        assert_eq!(
+            (pixel(7, 4), pixel(6, 4), pixel(7, 3)),
+            (Rgb::GREEN, Rgb::GREEN, Rgb::NAVY)
+        );
+        assert_eq!(pixel(7, 7), Rgb::YELLOW);
+        assert_eq!(pixel(0, 0), Rgb::NAVY);
+    }
+}
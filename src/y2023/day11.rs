@@ -0,0 +1,105 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::render::{Canvas, EColor, EStyle};
+
+#[aoc_generator(day11)]
+fn input_generator(input: &str) -> Result<Grid<bool>, ParseError> {
+    let grid = Grid::parse_with(11, input, "`#` or `.`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if grid.find(|&is_galaxy| is_galaxy).is_none() {
+        return Err(parse::missing(11, input, "at least one galaxy"));
+    }
+    Ok(grid)
+}
+
+/// Columns and rows without any galaxy, which expand
+fn empty_columns_and_rows(input: &Grid<bool>) -> (Vec<usize>, Vec<usize>) {
+    let columns = (0..input.width())
+        .filter(|&x| !input.column(x).any(|&is_galaxy| is_galaxy))
+        .collect();
+    let rows = (0..input.height())
+        .filter(|&y| !input.row(y).any(|&is_galaxy| is_galaxy))
+        .collect();
+    (columns, rows)
+}
+
+fn expand_universe(input: &Grid<bool>, expansion_factor: usize) -> Vec<Point> {
+    let (x_to_expand, y_to_expand) = empty_columns_and_rows(input);
+
+    let mut galaxies = vec![];
+    for (galaxy, _) in input.iter().filter(|(_, &is_galaxy)| is_galaxy) {
+        let add_x = x_to_expand
+            .iter()
+            .filter(|&&ex| (ex as i64) < galaxy.x)
+            .count();
+        let add_y = y_to_expand
+            .iter()
+            .filter(|&&ey| (ey as i64) < galaxy.y)
+            .count();
+        let expansion = Point::new(add_x as i64, add_y as i64) * expansion_factor as i64;
+        galaxies.push(galaxy + expansion);
+    }
+    galaxies
+}
+
+/// Distance between every pair of galaxies
+fn compute_distances(galaxies: &[Point]) -> Vec<u64> {
+    let mut res = vec![];
+    for (i, g1) in galaxies.iter().enumerate() {
+        for g2 in &galaxies[i + 1..] {
+            res.push(g1.manhattan(*g2));
+        }
+    }
+    res
+}
+
+#[aoc(day11, part1)]
+pub fn part1(input: &Grid<bool>) -> u64 {
+    let galaxies = expand_universe(input, 1);
+    compute_distances(&galaxies).iter().sum()
+}
+
+#[aoc(day11, part2)]
+pub fn part2(input: &Grid<bool>) -> u64 {
+    let galaxies = expand_universe(input, 1000000 - 1);
+    compute_distances(&galaxies).iter().sum()
+}
+
+/// Draw the image with its galaxies and the rows and columns that expand
+pub fn render(input: &str, style: EStyle) -> Result<String, ParseError> {
+    let grid = input_generator(input)?;
+    let (columns, rows) = empty_columns_and_rows(&grid);
+    let galaxies = grid
+        .iter()
+        .filter(|(_, &is_galaxy)| is_galaxy)
+        .map(|(pos, _)| pos);
+    let mut canvas = Canvas::new(&grid, |&is_galaxy| if is_galaxy { '#' } else { '.' });
+    canvas
+        .highlight_columns("expanded columns", EColor::Blue, columns)
+        .highlight_rows("expanded rows", EColor::Cyan, rows)
+        .highlight("galaxies", EColor::Yellow, galaxies);
+    Ok(canvas.render(style))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::render;
+    use crate::render::EStyle;
+
+    crate::example_tests!(2023, 11);
+    crate::answer_tests!(2023, 11);
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("#..\n...\n..#", EStyle::Plain).unwrap(),
+            "#..\n...\n..#\n\nexpanded columns: 3 cells\nexpanded rows: 3 cells\ngalaxies: 2 cells\n"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use std::{
+    cmp::Ordering::{Equal, Greater, Less},
+    str::FromStr,
+};
+
+use crate::memo::Memo;
+use crate::parse::{self, ParseError};
+
+type Row = (Vec<ESpringState>, Vec<u32>);
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ESpringState {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl FromStr for ESpringState {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(ESpringState::Operational),
+            "#" => Ok(ESpringState::Damaged),
+            "?" => Ok(ESpringState::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+#[aoc_generator(day12)]
+fn input_generator(input: &str) -> Result<Vec<Row>, ParseError> {
+    let mut res = vec![];
+    for line in parse::lines(12, input) {
+        let (springs_str, rules) = line.split_once(line.text, " ")?;
+        let springs = line.parse_chars(springs_str, "`.`, `#` or `?`", |c| {
+            c.encode_utf8(&mut [0; 4]).parse().ok()
+        })?;
+        let rules = line.integers(rules, "a damaged group size")?;
+        res.push((springs, rules))
+    }
+    Ok(res)
+}
+
+struct ProcessCount {
+    springs: Vec<ESpringState>,
+    rules: Vec<u32>,
+}
+
+impl ProcessCount {
+    fn new(springs: Vec<ESpringState>, rules: Vec<u32>) -> Self {
+        ProcessCount { springs, rules }
+    }
+
+    fn arrangements(&self) -> u64 {
+        let dims = [self.springs.len() + 1, self.rules.len() + 1];
+        let mut count = Memo::dense(dims, |count, [i, j]| self.count(count, i, j));
+        count.get([0, 0]) as u64
+    }
+
+    // `i` is the index in springs and `j` the index of the next rule to match,
+    // `count` is the memoized version of this function
+    fn count(&self, count: &mut dyn FnMut([usize; 2]) -> usize, i: usize, j: usize) -> usize {
+        if i >= self.springs.len() {
+            return if j == self.rules.len() { 1 } else { 0 };
+        }
+        match self.springs[i] {
+            ESpringState::Operational => count([i + 1, j]),
+            ESpringState::Damaged => self.count_hash(count, i, j),
+            ESpringState::Unknown => count([i + 1, j]) + self.count_hash(count, i, j),
+        }
+    }
+
+    // Count arrangements where a damaged group for rule `j` starts at `i`
+    fn count_hash(&self, count: &mut dyn FnMut([usize; 2]) -> usize, i: usize, j: usize) -> usize {
+        if j == self.rules.len() {
+            return 0;
+        }
+        let end_group_idx = i + self.rules[j] as usize;
+        if !self.is_rule_possible(i, end_group_idx) {
+            return 0;
+        }
+        if end_group_idx == self.springs.len() {
+            return if j == self.rules.len() - 1 { 1 } else { 0 };
+        }
+        // The spring right after the group is necessarily operational so we skip it
+        count([end_group_idx + 1, j + 1])
+    }
+
+    fn is_rule_possible(&self, from: usize, to: usize) -> bool {
+        match to.cmp(&self.springs.len()) {
+            Greater => false,
+            Equal => self.springs[from..to]
+                .iter()
+                .all(|&b| b != ESpringState::Operational),
+            Less => {
+                self.springs[from..to]
+                    .iter()
+                    .all(|&b| b != ESpringState::Operational)
+                    && self.springs[to] != ESpringState::Damaged
+            }
+        }
+    }
+}
+
+fn unfold(springs: &[ESpringState], rules: &[u32]) -> (Vec<ESpringState>, Vec<u32>) {
+    let mut unfolded_springs = springs.to_vec();
+    for _ in 0..4 {
+        unfolded_springs.push(ESpringState::Unknown);
+        unfolded_springs.extend_from_slice(springs);
+    }
+    (unfolded_springs, rules.repeat(5))
+}
+
+#[aoc(day12, part1)]
+pub fn part1(input: &[Row]) -> u64 {
+    input
+        .iter()
+        .map(|(springs, rules)| ProcessCount::new(springs.clone(), rules.clone()).arrangements())
+        .sum()
+}
+
+#[aoc(day12, part2)]
+pub fn part2(input: &[Row]) -> u64 {
+    input
+        .iter()
+        .map(|(springs, rules)| {
+            let (springs, rules) = unfold(springs, rules);
+            ProcessCount::new(springs, rules).arrangements()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+pub mod tests {
+    crate::example_tests!(2023, 12);
+    crate::answer_tests!(2023, 12);
+}
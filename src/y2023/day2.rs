@@ -0,0 +1,134 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::explain::Breakdown;
+use crate::parse::{self, FromPart, Line, LineFormat, ParseError};
+
+#[derive(LineFormat)]
+#[line("Game {id}: {sets:;}")]
+pub struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+impl Game {
+    fn is_possible(&self, bag: &Set) -> bool {
+        for set in &self.sets {
+            if set.blue > bag.blue || set.red > bag.red || set.green > bag.green {
+                return false;
+            }
+        }
+        true
+    }
+    /// Fewest cubes of each color that make the game possible
+    fn minimum_set(&self) -> Set {
+        let (mut blue, mut green, mut red) = (0, 0, 0);
+        for set in &self.sets {
+            if set.red > red {
+                red = set.red;
+            }
+            if set.green > green {
+                green = set.green;
+            }
+            if set.blue > blue {
+                blue = set.blue;
+            }
+        }
+        Set::new(blue, red, green)
+    }
+
+    fn get_power(&self) -> u32 {
+        let set = self.minimum_set();
+        set.red * set.green * set.blue
+    }
+}
+
+struct Set {
+    blue: u32,
+    red: u32,
+    green: u32,
+}
+
+impl Set {
+    pub fn new(blue: u32, red: u32, green: u32) -> Self {
+        Self { blue, red, green }
+    }
+}
+
+/// A set like `3 blue, 4 red`, missing colors have no cube
+impl FromPart for Set {
+    fn from_part<'a>(line: &Line<'a>, part: &'a str, _expected: &str) -> Result<Self, ParseError> {
+        let (mut blue, mut green, mut red) = (0, 0, 0);
+        for cubes in line.list(part, ",") {
+            let (nb, color) = line.key_value(cubes, " ")?;
+            let nb = line.parse::<u32>(nb, "a number of cubes")?;
+            match color {
+                "blue" => blue = nb,
+                "green" => green = nb,
+                "red" => red = nb,
+                _ => return Err(line.error(color, "`red`, `green` or `blue`")),
+            }
+        }
+        Ok(Set::new(blue, red, green))
+    }
+}
+
+/// The cubes of the bag in part 1
+const BAG: Set = Set {
+    blue: 14,
+    red: 12,
+    green: 13,
+};
+
+#[aoc_generator(day2)]
+fn input_generator(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse::parse_lines(2, input, "a game")
+}
+
+#[aoc(day2, part1)]
+pub fn part1(input: &[Game]) -> u32 {
+    input
+        .iter()
+        .filter(|game| game.is_possible(&BAG))
+        .fold(0, |acc, g| acc + g.id)
+}
+
+#[aoc(day2, part2)]
+pub fn part2(input: &[Game]) -> u32 {
+    input.iter().map(|game| game.get_power()).sum()
+}
+
+pub fn explain_part1(input: &str) -> Result<Breakdown, ParseError> {
+    let mut res = Breakdown::new(
+        "sum of the ids of the possible games, with 12 red, 13 green and 14 blue cubes",
+        &["game", "red", "green", "blue", "possible"],
+    );
+    for game in input_generator(input)? {
+        let set = game.minimum_set();
+        res.push(&[
+            &game.id,
+            &set.red,
+            &set.green,
+            &set.blue,
+            &game.is_possible(&BAG),
+        ]);
+    }
+    Ok(res)
+}
+
+pub fn explain_part2(input: &str) -> Result<Breakdown, ParseError> {
+    let mut res = Breakdown::new(
+        "sum of the powers of the fewest cubes of each game",
+        &["game", "red", "green", "blue", "power"],
+    );
+    for game in input_generator(input)? {
+        let set = game.minimum_set();
+        res.push(&[&game.id, &set.red, &set.green, &set.blue, &game.get_power()]);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+pub mod tests {
+    crate::example_tests!(2023, 2);
+    crate::answer_tests!(2023, 2);
+}
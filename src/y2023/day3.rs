@@ -0,0 +1,169 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::explain::Breakdown;
+use crate::geom::{Dir4, Point};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::render::{Canvas, EColor, EStyle};
+
+/// A number of the schematic with the positions of its digits
+pub struct PartNumber {
+    value: u32,
+    digits: Vec<Point>,
+}
+
+#[aoc_generator(day3)]
+fn input_generator(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_with(3, input, "a digit, a symbol or `.`", |c| {
+        (!c.is_whitespace()).then_some(c)
+    })
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+/// Digits are grouped into numbers as the components of their horizontal runs
+fn find_numbers(grid: &Grid<char>) -> Vec<PartNumber> {
+    let (labels, count) = grid.label_components(|a, b, dir| {
+        dir == Dir4::Right && a.is_ascii_digit() && b.is_ascii_digit()
+    });
+    let mut numbers: Vec<Option<PartNumber>> = (0..count).map(|_| None).collect();
+    // Row by row, the digits of a number are seen from left to right
+    for (pos, c) in grid.iter() {
+        if let Some(digit) = c.to_digit(10) {
+            let number = numbers[labels[pos]].get_or_insert(PartNumber {
+                value: 0,
+                digits: vec![],
+            });
+            number.value = number.value * 10 + digit;
+            number.digits.push(pos);
+        }
+    }
+    numbers.into_iter().flatten().collect()
+}
+
+/// Positions of the symbols touching a number, diagonals included
+fn adjacent_symbols(grid: &Grid<char>, number: &PartNumber) -> HashSet<Point> {
+    number
+        .digits
+        .iter()
+        .flat_map(|&digit| grid.neighbours8(digit))
+        .filter(|(_, &c)| is_symbol(c))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+#[aoc(day3, part1)]
+pub fn part1(input: &Grid<char>) -> u32 {
+    find_numbers(input)
+        .iter()
+        .filter(|number| !adjacent_symbols(input, number).is_empty())
+        .map(|number| number.value)
+        .sum()
+}
+
+/// Numbers next to each `*`, those with two numbers being gears
+fn gear_candidates(grid: &Grid<char>) -> HashMap<Point, Vec<u32>> {
+    let mut gears: HashMap<Point, Vec<u32>> = HashMap::new();
+    for number in find_numbers(grid) {
+        for symbol in adjacent_symbols(grid, &number) {
+            if grid[symbol] == '*' {
+                gears.entry(symbol).or_default().push(number.value);
+            }
+        }
+    }
+    gears
+}
+
+#[aoc(day3, part2)]
+pub fn part2(input: &Grid<char>) -> u32 {
+    gear_candidates(input)
+        .values()
+        .filter(|gear_nb| gear_nb.len() == 2)
+        .map(|gear_nb| gear_nb[0] * gear_nb[1])
+        .sum()
+}
+
+/// Draw the schematic with the part numbers, the other numbers and the gears
+pub fn render(input: &str, style: EStyle) -> Result<String, ParseError> {
+    let grid = input_generator(input)?;
+    let (part_numbers, others): (Vec<_>, Vec<_>) = find_numbers(&grid)
+        .into_iter()
+        .partition(|number| !adjacent_symbols(&grid, number).is_empty());
+    let digits = |numbers: Vec<PartNumber>| numbers.into_iter().flat_map(|n| n.digits);
+    let gears = gear_candidates(&grid)
+        .into_iter()
+        .filter(|(_, gear_nb)| gear_nb.len() == 2)
+        .map(|(pos, _)| pos);
+    let mut canvas = Canvas::new(&grid, |&c| c);
+    canvas
+        .highlight("part numbers", EColor::Green, digits(part_numbers))
+        .highlight("other numbers", EColor::Red, digits(others))
+        .highlight("gears", EColor::Yellow, gears);
+    Ok(canvas.render(style))
+}
+
+pub fn explain_part1(input: &str) -> Result<Breakdown, ParseError> {
+    let grid = input_generator(input)?;
+    let mut res = Breakdown::new(
+        "sum of the numbers next to a symbol",
+        &["number", "x", "y", "symbols", "part number"],
+    );
+    for number in find_numbers(&grid) {
+        let mut symbols: Vec<_> = adjacent_symbols(&grid, &number).into_iter().collect();
+        symbols.sort_by_key(|pos| (pos.y, pos.x));
+        let symbols: String = symbols.into_iter().map(|pos| grid[pos]).collect();
+        let Point { x, y } = number.digits[0];
+        let is_part = !symbols.is_empty();
+        res.push(&[&number.value, &x, &y, &symbols, &is_part]);
+    }
+    Ok(res)
+}
+
+pub fn explain_part2(input: &str) -> Result<Breakdown, ParseError> {
+    let grid = input_generator(input)?;
+    let mut res = Breakdown::new(
+        "sum of the ratios of the `*` next to exactly two numbers",
+        &["x", "y", "numbers", "ratio"],
+    );
+    let mut gears: Vec<_> = gear_candidates(&grid).into_iter().collect();
+    gears.sort_by_key(|&(pos, _)| (pos.y, pos.x));
+    for (Point { x, y }, numbers) in gears {
+        let ratio = if numbers.len() == 2 {
+            (numbers[0] * numbers[1]).to_string()
+        } else {
+            "-".to_string()
+        };
+        res.push(&[&x, &y, &format!("{numbers:?}"), &ratio]);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{input_generator, part1, part2, render};
+    use crate::render::EStyle;
+
+    crate::example_tests!(2023, 3);
+    crate::answer_tests!(2023, 3);
+
+    #[test]
+    fn test_symbol_on_border() {
+        let input = "*2.\n..+\n3..";
+        assert_eq!(part1(&input_generator(input).unwrap()), 2);
+        assert_eq!(part2(&input_generator(input).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "12.*3\n..*..\n4...5";
+        assert_eq!(
+            render(input, EStyle::Plain).unwrap(),
+            "12.*3\n..*..\n4...5\n\n\
+             part numbers: 3 cells\nother numbers: 2 cells\ngears: 0 cells\n"
+        );
+    }
+}
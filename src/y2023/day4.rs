@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::explain::Breakdown;
+use crate::parse::{self, LineFormat, ParseError};
+
+#[derive(LineFormat)]
+#[line("Card {id}: {win_nb} | {my_nb}")]
+pub struct Match {
+    id: u32,
+    win_nb: HashSet<u32>,
+    my_nb: HashSet<u32>,
+}
+
+#[aoc_generator(day4)]
+fn input_generator(input: &str) -> Result<Vec<Match>, ParseError> {
+    parse::parse_lines(4, input, "a card")
+}
+
+impl Match {
+    fn matching_numbers(&self) -> usize {
+        self.win_nb.intersection(&self.my_nb).count()
+    }
+
+    fn score(&self) -> u32 {
+        match self.matching_numbers() {
+            0 => 0,
+            matching_nb => u32::pow(2, matching_nb as u32 - 1),
+        }
+    }
+}
+
+#[aoc(day4, part1)]
+pub fn part1(input: &[Match]) -> u32 {
+    input.iter().map(Match::score).sum()
+}
+
+/// Number of copies of each card once every won copy is counted
+fn card_copies(input: &[Match]) -> HashMap<u32, u32> {
+    let mut results = input.iter().map(|x| (x.id, 1)).collect::<HashMap<_, _>>();
+    for m in input {
+        let matching_nb = m.matching_numbers();
+        let current_card_nb = *results.get(&m.id).unwrap_or(&1);
+        for i in m.id + 1..m.id + matching_nb as u32 + 1 {
+            let mut nb = 0;
+            if let Some(m_nb) = results.get(&i) {
+                nb += m_nb + current_card_nb;
+            };
+            crate::trace!("copies", card = i, won_by = m.id, copies = nb);
+            results.insert(i, nb);
+        }
+    }
+    results
+}
+
+#[aoc(day4, part2)]
+pub fn part2(input: &[Match]) -> u32 {
+    card_copies(input).values().sum()
+}
+
+pub fn explain_part1(input: &str) -> Result<Breakdown, ParseError> {
+    let mut res = Breakdown::new("sum of the card scores", &["card", "matching", "score"]);
+    for m in input_generator(input)? {
+        res.push(&[&m.id, &m.matching_numbers(), &m.score()]);
+    }
+    Ok(res)
+}
+
+pub fn explain_part2(input: &str) -> Result<Breakdown, ParseError> {
+    let cards = input_generator(input)?;
+    let copies = card_copies(&cards);
+    let mut res = Breakdown::new(
+        "sum of the copies of every card",
+        &["card", "matching", "copies"],
+    );
+    for m in &cards {
+        res.push(&[&m.id, &m.matching_numbers(), &copies[&m.id]]);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+pub mod tests {
+    crate::example_tests!(2023, 4);
+    crate::answer_tests!(2023, 4);
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::interval::{Interval, IntervalSet, RangeMap};
+use crate::parse::{self, ParseError};
+
+#[derive(Debug)]
+pub struct Map {
+    destination: String,
+    ranges: RangeMap,
+}
+
+#[aoc_generator(day5)]
+fn input_generator(input: &str) -> Result<(Vec<i64>, HashMap<String, Map>), ParseError> {
+    let mut res = HashMap::new();
+    let mut section_iter = parse::sections(5, input)?.into_iter();
+    let seeds_section = section_iter
+        .next()
+        .ok_or_else(|| parse::missing(5, input, "a `seeds:` line"))?;
+    let seeds: Vec<i64> = seeds_section
+        .header
+        .parse_all(seeds_section.value_of("seeds")?, "a seed number")?;
+    for section in section_iter {
+        let header = section.header;
+        let categories = header.strip_suffix(section.label, "map")?;
+        let (source, destination) = header.split_once(categories.trim(), "-to-")?;
+        let mut ranges = RangeMap::new();
+        for line in section.lines {
+            let range: Vec<i64> = line.parse_all(line.text, "a range number")?;
+            let [d_range_start, s_range_start, range_length] = range[..] else {
+                return Err(line.error(line.text, "3 range numbers"));
+            };
+            ranges.insert(
+                Interval::with_len(s_range_start, range_length),
+                d_range_start - s_range_start,
+            );
+        }
+        res.insert(
+            source.to_string(),
+            Map {
+                destination: destination.to_string(),
+                ranges,
+            },
+        );
+    }
+    if let Err(source) = maps_to_location(&res) {
+        let expected = format!("a `{source}-to-` map leading to `location` without a loop");
+        return Err(parse::missing(5, input, expected));
+    }
+    Ok((seeds, res))
+}
+
+/// Maps from seeds to locations, in order, or the category where the chain
+/// stops because it has no map or because its map was already used
+fn maps_to_location(categories: &HashMap<String, Map>) -> Result<Vec<&Map>, &str> {
+    let mut res: Vec<&Map> = vec![];
+    let mut source_map = "seed";
+    while source_map != "location" {
+        let map = categories
+            .get(source_map)
+            .filter(|map| !res.iter().any(|used| std::ptr::eq(*used, *map)))
+            .ok_or(source_map)?;
+        res.push(map);
+        source_map = map.destination.as_str();
+    }
+    Ok(res)
+}
+
+#[aoc(day5, part1)]
+pub fn part1(input: &(Vec<i64>, HashMap<String, Map>)) -> i64 {
+    let (seeds, categories) = input;
+    let maps = maps_to_location(categories).expect("checked by the generator");
+    seeds
+        .iter()
+        .map(|&seed| {
+            maps.iter().fold(seed, |value, map| {
+                let next = map.ranges.get(value);
+                crate::trace!(
+                    "map",
+                    seed = seed,
+                    category = &map.destination,
+                    value = next
+                );
+                next
+            })
+        })
+        .min()
+        .unwrap()
+}
+
+#[aoc(day5, part2)]
+pub fn part2(input: &(Vec<i64>, HashMap<String, Map>)) -> Result<i64, ParseError> {
+    let (source_seeds, categories) = input;
+    // only part 2 reads the seeds as ranges, part 1 takes any number of them
+    let pairs = source_seeds.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(ParseError {
+            day: 5,
+            line: 1,
+            column: 1,
+            text: format!("{} seed numbers", source_seeds.len()),
+            expected: "pairs of seed numbers, a start and a length per range".to_string(),
+        });
+    }
+    let seeds: IntervalSet = pairs
+        .map(|pair| Interval::with_len(pair[0], pair[1]))
+        .collect();
+    let locations = maps_to_location(categories)
+        .expect("checked by the generator")
+        .iter()
+        .fold(seeds, |values, map| {
+            let next = map.ranges.apply(&values);
+            crate::trace!(
+                "map",
+                category = &map.destination,
+                intervals = next.iter().count(),
+                values = next.len()
+            );
+            next
+        });
+    Ok(locations.min().unwrap())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{input_generator, part1, part2};
+    use crate::parse::ParseError;
+    use crate::property::{self, shrink_i64, shrink_vec, Rng};
+    use crate::{runner, y2023};
+
+    crate::example_tests!(2023, 5);
+    crate::answer_tests!(2023, 5);
+
+    /// Seed ranges as `(start, len)` and maps as `(destination, source, len)`
+    #[derive(Debug, Clone)]
+    struct Almanac {
+        seeds: Vec<(i64, i64)>,
+        maps: Vec<Vec<(i64, i64, i64)>>,
+    }
+
+    impl Almanac {
+        fn generate(rng: &mut Rng) -> Self {
+            let seeds = rng.vec(1..=3, |rng| (rng.range(0..=50), rng.range(1..=10)));
+            let maps = rng.vec(1..=4, |rng| {
+                // sources one after the other, as they never overlap in inputs
+                let mut source = rng.range(0..=10);
+                rng.vec(0..=4, |rng| {
+                    let range = (rng.range(0..=60), source, rng.range(1..=15));
+                    source += range.2 + rng.range(0..=10);
+                    range
+                })
+            });
+            Almanac { seeds, maps }
+        }
+
+        fn is_valid(&self) -> bool {
+            !self.seeds.is_empty()
+                && !self.maps.is_empty()
+                && self.seeds.iter().all(|&(_, len)| len > 0)
+                && self.maps.iter().all(|ranges| {
+                    ranges.iter().all(|&(_, _, len)| len > 0)
+                        && ranges.iter().enumerate().all(|(i, &(_, a, a_len))| {
+                            ranges[i + 1..]
+                                .iter()
+                                .all(|&(_, b, b_len)| a + a_len <= b || b + b_len <= a)
+                        })
+                })
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let seeds = shrink_vec(&self.seeds, |&(start, len)| {
+                let starts = shrink_i64(start).into_iter().map(move |s| (s, len));
+                starts
+                    .chain(shrink_i64(len).into_iter().map(move |l| (start, l)))
+                    .collect()
+            });
+            let maps = shrink_vec(&self.maps, |ranges| {
+                shrink_vec(ranges, |&(destination, source, len)| {
+                    let mut res = vec![];
+                    res.extend(
+                        shrink_i64(destination)
+                            .into_iter()
+                            .map(|d| (d, source, len)),
+                    );
+                    res.extend(
+                        shrink_i64(source)
+                            .into_iter()
+                            .map(|s| (destination, s, len)),
+                    );
+                    res.extend(
+                        shrink_i64(len)
+                            .into_iter()
+                            .map(|l| (destination, source, l)),
+                    );
+                    res
+                })
+            });
+            let seeds = seeds.into_iter().map(|seeds| Almanac {
+                seeds,
+                maps: self.maps.clone(),
+            });
+            let maps = maps.into_iter().map(|maps| Almanac {
+                seeds: self.seeds.clone(),
+                maps,
+            });
+            seeds.chain(maps).filter(Almanac::is_valid).collect()
+        }
+
+        /// The puzzle input, with the given seed numbers
+        fn to_input(&self, seeds: &[i64]) -> String {
+            let seeds: Vec<_> = seeds.iter().map(i64::to_string).collect();
+            let mut res = format!("seeds: {}\n", seeds.join(" "));
+            for (i, ranges) in self.maps.iter().enumerate() {
+                let source = if i == 0 {
+                    "seed".to_string()
+                } else {
+                    format!("c{i}")
+                };
+                let destination = if i + 1 == self.maps.len() {
+                    "location".to_string()
+                } else {
+                    format!("c{}", i + 1)
+                };
+                res.push_str(&format!("\n{source}-to-{destination} map:\n"));
+                for (d, s, len) in ranges {
+                    res.push_str(&format!("{d} {s} {len}\n"));
+                }
+            }
+            res
+        }
+    }
+
+    #[test]
+    fn test_invalid_almanacs() {
+        let error = |input: &str| input_generator(input).map(|_| ()).unwrap_err();
+        let missing = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3";
+        assert_eq!(
+            error(missing),
+            ParseError {
+                day: 5,
+                line: 5,
+                column: 1,
+                text: String::new(),
+                expected: "a `soil-to-` map leading to `location` without a loop".to_string()
+            }
+        );
+        let looping = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3";
+        assert_eq!(
+            error(looping).expected,
+            "a `seed-to-` map leading to `location` without a loop"
+        );
+    }
+
+    #[test]
+    fn test_odd_seed_count() {
+        let input = "seeds: 1 2 3\n\nseed-to-location map:\n10 0 5";
+        let almanac = input_generator(input).unwrap();
+        assert_eq!(part1(&almanac), 11);
+        let error = part2(&almanac).unwrap_err();
+        assert_eq!(
+            error.expected,
+            "pairs of seed numbers, a start and a length per range"
+        );
+        let part2 = y2023::YEAR.solution(5, 2).unwrap();
+        assert!(runner::run(part2, input).is_err());
+    }
+
+    #[test]
+    fn test_ranges_match_brute_force() {
+        property::assert(5, 2000, Almanac::generate, Almanac::shrink, |almanac| {
+            let pairs: Vec<_> = almanac.seeds.iter().flat_map(|&(s, l)| [s, l]).collect();
+            let ranges = input_generator(&almanac.to_input(&pairs)).map_err(|e| e.to_string())?;
+            // part 1 tries each seed of the ranges
+            let every_seed = almanac.seeds.iter().flat_map(|&(s, l)| s..s + l).collect();
+            let found = part2(&ranges).map_err(|e| e.to_string())?;
+            let expected = part1(&(every_seed, ranges.1));
+            (found == expected)
+                .then_some(())
+                .ok_or(format!("lowest location {found}, {expected} expected"))
+        });
+    }
+}
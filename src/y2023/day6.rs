@@ -0,0 +1,226 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::explain::Breakdown;
+use crate::parse::{self, Line, ParseError};
+
+/// The `Time:` and `Distance:` lines with their values, which list the same
+/// number of races, at least one
+fn race_lines(input: &str) -> Result<[(Line<'_>, &str); 2], ParseError> {
+    let mut lines = parse::lines(6, input);
+    let times_line = lines
+        .next()
+        .ok_or_else(|| parse::missing(6, input, "a `Time:` line"))?;
+    let distances_line = lines
+        .next()
+        .ok_or_else(|| parse::missing(6, input, "a `Distance:` line"))?;
+    let times = times_line.label(times_line.text, "Time")?;
+    let distances = distances_line.label(distances_line.text, "Distance")?;
+    let races = times.split_whitespace().count();
+    if races == 0 {
+        return Err(times_line.error(times, "at least one race time"));
+    }
+    if distances.split_whitespace().count() != races {
+        return Err(distances_line.error(distances_line.text, format!("{races} race distances")));
+    }
+    Ok([(times_line, times), (distances_line, distances)])
+}
+
+#[aoc_generator(day6, part1)]
+fn input_generator_part1(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let [(times_line, times), (distances_line, distances)] = race_lines(input)?;
+    let times: Vec<u64> = times_line.parse_all(times, "a race time")?;
+    let distances: Vec<u64> = distances_line.parse_all(distances, "a race distance")?;
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+/// The single race of part 2, whose digits are those of every race
+#[aoc_generator(day6, part2)]
+fn input_generator_part2(input: &str) -> Result<(u64, u64), ParseError> {
+    let [(times_line, times), (distances_line, distances)] = race_lines(input)?;
+    let kerned = |line: Line, values: &str, expected: &str| {
+        let digits: String = values.split_whitespace().collect();
+        digits.parse().map_err(|_| line.error(values, expected))
+    };
+    Ok((
+        kerned(times_line, times, "the digits of a 64 bits race time")?,
+        kerned(
+            distances_line,
+            distances,
+            "the digits of a 64 bits race distance",
+        )?,
+    ))
+}
+
+fn is_race_win(speed: u64, race_time: u64, best_distance: u64) -> bool {
+    let run_time = race_time - speed;
+    // the product of two 64 bits numbers only fits in 128 bits
+    let distance_reached = run_time as u128 * speed as u128;
+    distance_reached > best_distance as u128
+}
+
+/// Number of hold times beating the record, trying each of them
+fn race_wins(time: u64, distance: u64) -> u64 {
+    (0..time)
+        .filter(|&hold_time| is_race_win(hold_time, time, distance))
+        .count() as u64
+}
+
+#[aoc(day6, part1)]
+pub fn part1(input: &[(u64, u64)]) -> u64 {
+    input
+        .iter()
+        .map(|&(time, distance)| race_wins(time, distance))
+        .product()
+}
+
+/// First hold time of `min..=max` winning the race, winning hold times being
+/// after the losing ones in that range
+pub fn binary_search(min: u64, max: u64, race_time: u64, best_distance: u64) -> u64 {
+    let (mut min, mut max) = (min, max);
+    while min < max {
+        let mid = (min + max) / 2;
+        if is_race_win(mid, race_time, best_distance) {
+            max = mid;
+        } else {
+            min = mid + 1;
+        }
+    }
+    min
+}
+
+/// Number of hold times beating the record, searching the first winning one
+fn race_wins_search(time: u64, distance: u64) -> u64 {
+    if !is_race_win(time / 2, time, distance) {
+        return 0;
+    }
+    // the distance of a hold time is that of `time` minus it, so the winning
+    // hold times are `first..=time - first`
+    let first = binary_search(0, time / 2, time, distance);
+    time - 2 * first + 1
+}
+
+#[aoc(day6, part2)]
+pub fn part2(input: &(u64, u64)) -> u64 {
+    let &(time, distance) = input;
+    race_wins_search(time, distance)
+}
+
+pub fn explain_part1(input: &str) -> Result<Breakdown, ParseError> {
+    let mut res = Breakdown::new(
+        "product of the ways to win each race",
+        &["race", "time", "record", "wins"],
+    );
+    for (i, (time, distance)) in input_generator_part1(input)?.into_iter().enumerate() {
+        res.push(&[&(i + 1), &time, &distance, &race_wins(time, distance)]);
+    }
+    Ok(res)
+}
+
+pub fn explain_part2(input: &str) -> Result<Breakdown, ParseError> {
+    let race = input_generator_part2(input)?;
+    let (time, distance) = race;
+    let mut res = Breakdown::new(
+        "ways to win the race read without spaces",
+        &["time", "record", "wins"],
+    );
+    res.push(&[&time, &distance, &part2(&race)]);
+    Ok(res)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{
+        explain_part1, explain_part2, input_generator_part1, input_generator_part2, part1, part2,
+        race_wins, race_wins_search,
+    };
+    use crate::property::{self, shrink_u64, Rng};
+
+    crate::example_tests!(2023, 6);
+    crate::answer_tests!(2023, 6);
+
+    /// A race whose record is around the best distance, most being winnable
+    fn race(rng: &mut Rng, max_time: u64) -> (u64, u64) {
+        let time = rng.range_u64(0..=max_time);
+        let best = (time / 2) * (time - time / 2);
+        (time, rng.range_u64(0..=best + 5))
+    }
+
+    fn shrink_race(&(time, distance): &(u64, u64)) -> Vec<(u64, u64)> {
+        let times = shrink_u64(time).into_iter().map(|t| (t, distance));
+        let distances = shrink_u64(distance).into_iter().map(|d| (time, d));
+        times.chain(distances).collect()
+    }
+
+    #[test]
+    fn test_race_wins_search() {
+        // the search used to count one win too many, or to underflow, on these
+        for (time, distance, wins) in [(2, 0, 1), (15, 41, 8), (3, 1, 2), (4, 4, 0), (0, 0, 0)] {
+            assert_eq!(race_wins(time, distance), wins, "{time} {distance}");
+            assert_eq!(race_wins_search(time, distance), wins, "{time} {distance}");
+        }
+    }
+
+    #[test]
+    fn test_search_matches_brute_force() {
+        let generate = |rng: &mut Rng| race(rng, 200);
+        property::assert(6, 5000, generate, shrink_race, |&(time, distance)| {
+            let expected = race_wins(time, distance);
+            let found = race_wins_search(time, distance);
+            (found == expected)
+                .then_some(())
+                .ok_or(format!("{found} wins found, {expected} expected"))
+        });
+    }
+
+    #[test]
+    fn test_invalid_races() {
+        let error = |input: &str| input_generator_part2(input).unwrap_err();
+        assert_eq!(error("Time:\nDistance:").expected, "at least one race time");
+        assert_eq!(
+            error("Time: 7 15\nDistance: 9").expected,
+            "2 race distances"
+        );
+        assert!(input_generator_part1("Time:\nDistance:").is_err());
+        // each number fits in 64 bits but not the kerned one
+        let error = error("Time: 7 8\nDistance: 9999999999 9999999999");
+        assert_eq!(error.expected, "the digits of a 64 bits race distance");
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(race_wins_search(u64::MAX, u64::MAX), u64::MAX - 3);
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        let breakdown = explain_part1(input).unwrap();
+        let wins: Vec<_> = breakdown.rows.iter().map(|row| row[3].as_str()).collect();
+        assert_eq!(wins, ["4", "8", "9"]);
+        let breakdown = explain_part2(input).unwrap();
+        assert_eq!(breakdown.rows, [["71530", "940200", "71503"]]);
+    }
+
+    #[test]
+    fn test_part2_matches_brute_force() {
+        // at most two races of two digits, for a kerned race short enough to try
+        let generate = |rng: &mut Rng| rng.vec(1..=2, |rng| race(rng, 99));
+        let shrink = |races: &Vec<(u64, u64)>| {
+            let mut res = property::shrink_vec(races, shrink_race);
+            res.retain(|races| !races.is_empty());
+            res
+        };
+        property::assert(6, 2000, generate, shrink, |races| {
+            let times: Vec<_> = races.iter().map(|(t, _)| t.to_string()).collect();
+            let distances: Vec<_> = races.iter().map(|(_, d)| d.to_string()).collect();
+            let input = format!(
+                "Time: {}\nDistance: {}",
+                times.join(" "),
+                distances.join(" ")
+            );
+            let race = input_generator_part2(&input).map_err(|e| e.to_string())?;
+            let expected = part1(&[race]);
+            let found = part2(&race);
+            (found == expected)
+                .then_some(())
+                .ok_or(format!("{found} wins found, {expected} expected"))
+        });
+    }
+}
@@ -1,8 +1,12 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::fmt::Debug;
 
 use aoc_runner_derive::{aoc, aoc_generator};
 
+use crate::explain::Breakdown;
+use crate::parse::{self, Line, ParseError};
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum EVersion {
     V1,
@@ -102,99 +106,139 @@ impl PartialOrd for HandAndBid {
     }
 }
 
+fn parse_hand<'a>(line: &Line<'a>, hand: &'a str) -> Result<&'a str, ParseError> {
+    if hand.len() != 5 {
+        return Err(line.error(hand, "a hand of 5 cards"));
+    }
+    if let Some(i) = hand.find(|c: char| !"23456789TJQKA".contains(c)) {
+        return Err(line.error(&hand[i..], "a card among `23456789TJQKA`"));
+    }
+    Ok(hand)
+}
+
 #[aoc_generator(day7, part1)]
-fn input_generator_part1(input: &str) -> Vec<HandAndBid> {
+fn input_generator_part1(input: &str) -> Result<Vec<HandAndBid>, ParseError> {
     let mut res = vec![];
-    for line in input.lines() {
-        let (hand, bid) = line.trim().split_once(' ').unwrap();
+    for line in parse::lines(7, input) {
+        let (hand, bid) = line.split_once(line.text, " ")?;
+        let hand = parse_hand(&line, hand)?;
         let mut card_counts: HashMap<char, u32> = HashMap::new();
         for c in hand.chars() {
             *card_counts.entry(c).or_insert(0) += 1;
         }
         let mut card_counts: Vec<(char, u32)> = card_counts.into_iter().collect();
-        card_counts.sort_by(|a, b| b.1.cmp(&a.1));
+        card_counts.sort_by_key(|a| Reverse(a.1));
         res.push(HandAndBid {
             hand: hand.to_string(),
             cards: card_counts,
-            bid: bid.parse().unwrap(),
+            bid: line.parse(bid, "a bid")?,
             version: EVersion::V1,
         });
     }
-    res
+    Ok(res)
 }
 
 #[aoc_generator(day7, part2)]
-fn input_generator_part2(input: &str) -> Vec<HandAndBid> {
+fn input_generator_part2(input: &str) -> Result<Vec<HandAndBid>, ParseError> {
     let mut res = vec![];
-    for line in input.lines() {
-        let (hand, bid) = line.trim().split_once(' ').unwrap();
+    for line in parse::lines(7, input) {
+        let (hand, bid) = line.split_once(line.text, " ")?;
+        let hand = parse_hand(&line, hand)?;
         let mut card_counts: HashMap<char, u32> = HashMap::new();
         for c in hand.chars() {
             *card_counts.entry(c).or_insert(0) += 1;
         }
         let j_count = card_counts.remove(&'J').unwrap_or(0);
         let mut card_counts: Vec<(char, u32)> = card_counts.into_iter().collect();
-        card_counts.sort_by(|a, b| b.1.cmp(&a.1));
+        card_counts.sort_by_key(|a| Reverse(a.1));
         res.push(HandAndBid {
             hand: hand.to_string(),
             cards: card_counts,
-            bid: bid.parse().unwrap(),
+            bid: line.parse(bid, "a bid")?,
             version: EVersion::V2(j_count),
         });
     }
-    res
+    Ok(res)
 }
 
-#[aoc(day7, part1)]
-pub fn part1(input: &[HandAndBid]) -> u32 {
+/// `Ord` of the hands, recording each comparison of the sort
+fn compare_traced(a: &HandAndBid, b: &HandAndBid) -> std::cmp::Ordering {
+    let order = a.cmp(b);
+    crate::trace!(
+        "compare",
+        left = &a.hand,
+        right = &b.hand,
+        order = format!("{order:?}")
+    );
+    order
+}
+
+/// The hands from the weakest to the strongest, whose rank is their place
+fn ranked(input: &[HandAndBid]) -> Vec<HandAndBid> {
     let mut res = input.to_vec();
-    res.sort();
-    res.into_iter()
+    res.sort_by(compare_traced);
+    res
+}
+
+fn total_winnings(input: &[HandAndBid]) -> u32 {
+    ranked(input)
+        .into_iter()
         .enumerate()
         .map(|(i, HandAndBid { bid, .. })| (i as u32 + 1) * bid)
         .sum()
 }
 
+#[aoc(day7, part1)]
+pub fn part1(input: &[HandAndBid]) -> u32 {
+    total_winnings(input)
+}
+
 #[aoc(day7, part2)]
 pub fn part2(input: &[HandAndBid]) -> u32 {
-    let mut res = input.to_vec();
-    res.sort();
-    res.into_iter()
-        .enumerate()
-        .map(|(i, HandAndBid { bid, .. })| (i as u32 + 1) * bid)
-        .sum()
+    total_winnings(input)
 }
 
-#[cfg(test)]
-pub mod tests {
-    use super::{input_generator_part1, input_generator_part2, part1, part2};
-    use std::fs;
+fn explain(input: &[HandAndBid]) -> Breakdown {
+    let mut res = Breakdown::new(
+        "sum of the bids times the ranks",
+        &["rank", "hand", "bid", "winnings"],
+    );
+    for (i, hand) in ranked(input).iter().enumerate() {
+        let rank = i as u32 + 1;
+        res.push(&[&rank, &hand.hand, &hand.bid, &(rank * hand.bid)]);
+    }
+    res
+}
 
-    const INPUT: &str = "32T3K 765
-        T55J5 684
-        KK677 28
-        KTJJT 220
-        QQQJA 483";
+pub fn explain_part1(input: &str) -> Result<Breakdown, ParseError> {
+    Ok(explain(&input_generator_part1(input)?))
+}
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(&input_generator_part1(INPUT)), 6440);
-    }
+pub fn explain_part2(input: &str) -> Result<Breakdown, ParseError> {
+    Ok(explain(&input_generator_part2(input)?))
+}
 
-    #[test]
-    fn test_part1_input() {
-        let input = fs::read_to_string("input/2023/day7.txt").unwrap();
-        assert_eq!(part1(&input_generator_part1(&input)), 251287184);
-    }
+#[cfg(test)]
+pub mod tests {
+    use super::{explain_part1, explain_part2};
+    use crate::example;
 
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(&input_generator_part2(INPUT)), 5905);
-    }
+    crate::example_tests!(2023, 7);
+    crate::answer_tests!(2023, 7);
 
     #[test]
-    fn test_part2_input() {
-        let input = fs::read_to_string("input/2023/day7.txt").unwrap();
-        assert_eq!(part2(&input_generator_part2(&input)), 250757288);
+    fn test_explain_sums_to_answer() {
+        for example in example::load(2023, 7).unwrap() {
+            for (explain, answer) in [explain_part1, explain_part2].iter().zip(&example.answers) {
+                let Some(answer) = answer else { continue };
+                let breakdown = explain(&example.input).unwrap();
+                let winnings: u32 = breakdown
+                    .rows
+                    .iter()
+                    .map(|row| row[3].parse::<u32>().unwrap())
+                    .sum();
+                assert_eq!(&winnings.to_string(), answer, "{:?}", example.path);
+            }
+        }
     }
 }
@@ -0,0 +1,172 @@
+use std::fmt::Debug;
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::cycle::{self, Cycle};
+use crate::parse::{self, ParseError};
+
+pub type Instructons = HashMap<String, (String, String)>;
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum EDirection {
+    Left,
+    Right,
+}
+
+impl FromStr for EDirection {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(EDirection::Left),
+            "R" => Ok(EDirection::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+#[aoc_generator(day8)]
+fn input_generator(input: &str) -> Result<(Vec<EDirection>, Instructons), ParseError> {
+    let mut res = HashMap::new();
+    let mut block_iter = parse::blocks(8, input).into_iter();
+    let directions_block = block_iter
+        .next()
+        .ok_or_else(|| parse::missing(8, input, "a line of directions"))?;
+    let directions_line = directions_block[0];
+    let directions = directions_line.parse_chars(directions_line.text, "`L` or `R`", |x| {
+        EDirection::from_str(x.encode_utf8(&mut [0; 4])).ok()
+    })?;
+    let mut links = vec![];
+    for line in block_iter.flatten() {
+        let (source, destination) = line.key_value(line.text, "=")?;
+        let destination = line.delimited(destination, "(", ")")?;
+        let (d_left, d_right) = line.key_value(destination, ",")?;
+        res.insert(
+            source.to_string(),
+            (d_left.to_string(), d_right.to_string()),
+        );
+        links.push((line, [d_left, d_right]));
+    }
+    // nodes may be used before the line defining them
+    for (line, destinations) in links {
+        if let Some(unknown) = destinations.into_iter().find(|d| !res.contains_key(*d)) {
+            return Err(line.error(unknown, "a node defined by a line"));
+        }
+    }
+    Ok((directions, res))
+}
+
+#[aoc(day8, part1)]
+pub fn part1(input: &(Vec<EDirection>, Instructons)) -> Result<u32, String> {
+    let (directions, instructions) = input;
+    let mut current_position = "AAA";
+    if !instructions.contains_key(current_position) {
+        return Err("no `AAA` node to start from".to_string());
+    }
+    let mut step = 0;
+    while current_position != "ZZZ" {
+        // destinations are checked by the generator
+        let instruction = &instructions[current_position];
+        let direction = directions[step % directions.len()];
+        crate::trace!(
+            "step",
+            step = step,
+            node = current_position,
+            direction = format!("{direction:?}")
+        );
+        match direction {
+            EDirection::Left => {
+                current_position = &instruction.0;
+            }
+            EDirection::Right => {
+                current_position = &instruction.1;
+            }
+        }
+        step += 1;
+    }
+    Ok(step as u32)
+}
+
+#[aoc(day8, part2)]
+pub fn part2(input: &(Vec<EDirection>, Instructons)) -> Result<u64, String> {
+    let (directions, instructions) = input;
+    // Nodes are numbered so that stepping a ghost does not hash strings
+    let mut nodes: Vec<&str> = instructions.keys().map(String::as_str).collect();
+    nodes.sort();
+    let index: HashMap<&str, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let links: Vec<[usize; 2]> = nodes
+        .iter()
+        .map(|&n| {
+            let (left, right) = &instructions[n];
+            [index[left.as_str()], index[right.as_str()]]
+        })
+        .collect();
+    // A ghost's state is its node and its place in the directions. Its first Z
+    // needs not be its cycle length, so each cycle is detected and the common
+    // hit is solved with congruences rather than a plain lcm.
+    let mut cycles: Vec<Cycle> = vec![];
+    for start in (0..nodes.len()).filter(|&n| nodes[n].ends_with('A')) {
+        let cycle = cycle::brent(
+            (start, 0),
+            |&(node, i)| {
+                let next = match directions[i] {
+                    EDirection::Left => links[node][0],
+                    EDirection::Right => links[node][1],
+                };
+                (next, (i + 1) % directions.len())
+            },
+            |&(node, _)| nodes[node].ends_with('Z'),
+        );
+        crate::trace!(
+            "ghost",
+            start = nodes[start],
+            prefix = cycle.prefix,
+            len = cycle.len,
+            hits = format!("{:?}", cycle.hits)
+        );
+        if cycle.hits.is_empty() {
+            return Err(format!(
+                "the ghost starting at {} never reaches a node ending with Z",
+                nodes[start]
+            ));
+        }
+        cycles.push(cycle);
+    }
+    cycle::first_common_hit(&cycles)
+        .ok_or_else(|| "the ghosts are never all on a node ending with Z within u64 steps".into())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{input_generator, part1, part2};
+
+    crate::example_tests!(2023, 8);
+    crate::answer_tests!(2023, 8);
+
+    #[test]
+    fn test_undefined_node() {
+        let error = input_generator("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZY)\nZZZ = (ZZZ, ZZZ)")
+            .unwrap_err();
+        assert_eq!(error.expected, "a node defined by a line");
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (4, 13, "ZZY")
+        );
+        let network = input_generator("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)").unwrap();
+        assert!(part1(&network).is_err());
+    }
+
+    #[test]
+    fn test_ghosts_never_meeting() {
+        // 22A only loops between 22A and 22B
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22A, 22A)";
+        let error = part2(&input_generator(input).unwrap()).unwrap_err();
+        assert_eq!(
+            error,
+            "the ghost starting at 22A never reaches a node ending with Z"
+        );
+        // both reach Z, on odd steps for one and even steps for the other
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+        let error = part2(&input_generator(input).unwrap()).unwrap_err();
+        assert!(error.starts_with("the ghosts are never all"), "{error}");
+    }
+}
@@ -1,16 +1,14 @@
 use aoc_runner_derive::{aoc, aoc_generator};
 
+use crate::parse::{self, ParseError};
+
 #[aoc_generator(day9)]
-fn input_generator(input: &str) -> Vec<Vec<i32>> {
+fn input_generator(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
     let mut res = vec![];
-    for line in input.lines() {
-        let values = line
-            .split_whitespace()
-            .map(|x| x.parse::<i32>().unwrap())
-            .collect();
-        res.push(values);
+    for line in parse::lines(9, input) {
+        res.push(line.parse_all(line.text, "a history value")?);
     }
-    res
+    Ok(res)
 }
 
 fn compute_diff_steps(input: &[i32]) -> Vec<Vec<i32>> {
@@ -65,32 +63,6 @@ pub fn part2(input: &Vec<Vec<i32>>) -> i32 {
 
 #[cfg(test)]
 pub mod tests {
-    use super::{input_generator, part1, part2};
-    use std::fs;
-
-    const INPUT: &str = "0 3 6 9 12 15
-        1 3 6 10 15 21
-        10 13 16 21 30 45";
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(&input_generator(INPUT)), 114);
-    }
-
-    #[test]
-    fn test_part1_input() {
-        let input = fs::read_to_string("input/2023/day9.txt").unwrap();
-        assert_eq!(part1(&input_generator(&input)), 2101499000);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(&input_generator(INPUT)), 2);
-    }
-
-    #[test]
-    fn test_part2_input() {
-        let input = fs::read_to_string("input/2023/day9.txt").unwrap();
-        assert_eq!(part2(&input_generator(&input)), 1089);
-    }
+    crate::example_tests!(2023, 9);
+    crate::answer_tests!(2023, 9);
 }
@@ -0,0 +1,100 @@
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use crate::runner::{Solution, Year};
+// `Factory` and the `DayNPartM` traits it implements are generated by `aoc_lib!`
+use crate::aoc_factory::*;
+
+/// Every `#[aoc]` solution of the year, sorted by day then part
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::new(1, 1, Factory::day1_part1),
+    Solution::new(1, 2, Factory::day1_part2),
+    Solution::new(2, 1, Factory::day2_part1),
+    Solution::new(2, 2, Factory::day2_part2),
+    Solution::new(3, 1, Factory::day3_part1),
+    Solution::new(3, 2, Factory::day3_part2),
+    Solution::new(4, 1, Factory::day4_part1),
+    Solution::new(4, 2, Factory::day4_part2),
+    Solution::new(5, 1, Factory::day5_part1),
+    Solution::new(5, 2, Factory::day5_part2),
+    Solution::new(6, 1, Factory::day6_part1),
+    Solution::new(6, 2, Factory::day6_part2),
+    Solution::new(7, 1, Factory::day7_part1),
+    Solution::new(7, 2, Factory::day7_part2),
+    Solution::new(8, 1, Factory::day8_part1),
+    Solution::new(8, 2, Factory::day8_part2),
+    Solution::new(9, 1, Factory::day9_part1),
+    Solution::new(9, 2, Factory::day9_part2),
+    Solution::new(10, 1, Factory::day10_part1),
+    Solution::new(10, 2, Factory::day10_part2),
+    Solution::new(11, 1, Factory::day11_part1),
+    Solution::new(11, 2, Factory::day11_part2),
+    Solution::new(12, 1, Factory::day12_part1),
+    Solution::new(12, 2, Factory::day12_part2),
+];
+
+pub const YEAR: Year = Year {
+    year: 2023,
+    solutions: SOLUTIONS,
+    renders: &[(3, day3::render), (10, day10::render), (11, day11::render)],
+    images: &[(10, day10::draw)],
+    explains: &[
+        (1, 1, day1::explain_part1),
+        (1, 2, day1::explain_part2),
+        (2, 1, day2::explain_part1),
+        (2, 2, day2::explain_part2),
+        (3, 1, day3::explain_part1),
+        (3, 2, day3::explain_part2),
+        (4, 1, day4::explain_part1),
+        (4, 2, day4::explain_part2),
+        (6, 1, day6::explain_part1),
+        (6, 2, day6::explain_part2),
+        (7, 1, day7::explain_part1),
+        (7, 2, day7::explain_part2),
+    ],
+};
+
+#[cfg(test)]
+pub mod tests {
+    use std::{fs, path::Path};
+
+    use super::SOLUTIONS;
+
+    /// Every `#[aoc(dayN, partM)]` of the modules of the year is in `SOLUTIONS`,
+    /// which is kept by hand
+    #[test]
+    fn test_solutions_are_complete() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/y2023");
+        let mut parts = vec![];
+        for entry in fs::read_dir(&dir).unwrap() {
+            let source = fs::read_to_string(entry.unwrap().path()).unwrap();
+            for line in source.lines() {
+                let Some(attribute) = line.trim().strip_prefix("#[aoc(") else {
+                    continue;
+                };
+                let (day, part) = attribute
+                    .trim_end_matches(")]")
+                    .split_once(", ")
+                    .unwrap_or_else(|| panic!("unexpected attribute {line:?}"));
+                let number = |s: &str, prefix| s.strip_prefix(prefix)?.parse::<u32>().ok();
+                parts.push((number(day, "day").unwrap(), number(part, "part").unwrap()));
+            }
+        }
+        parts.sort_unstable();
+        let registered: Vec<_> = SOLUTIONS.iter().map(|s| (s.day, s.part)).collect();
+        assert_eq!(
+            registered, parts,
+            "`SOLUTIONS` differs from the `#[aoc]` parts"
+        );
+    }
+}